@@ -1,52 +1,205 @@
-use super::macro_args::MacroArgs;
+use super::macro_args::{enum_repr, enum_variants, parse_attributes, MacroArgs, StructArgs};
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
-use quote::quote;
-use syn::{
-    parse_macro_input, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
-    Fields,
-};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DataEnum, DataStruct, DeriveInput, Field, Fields};
+
+fn create_align(align: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        let align = #align;
+        let padding = ::no_std_io::padding_to_align(::no_std_io::Cursor::get_index(&stream), align);
+        ::no_std_io::StreamReader::try_increment_by(&mut stream, padding)?;
+    }
+}
+
+fn create_field_ident(index: usize, field: &Field) -> Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => format_ident!("field_{}", index),
+    }
+}
+
+fn create_remaining_size(total_size: &Ident) -> proc_macro2::TokenStream {
+    quote! {{
+        let total_size: usize = ::core::convert::TryFrom::try_from(#total_size).map_err(|_| {
+            ::no_std_io::Error::InvalidRead {
+                message: "Invalid size",
+            }
+        })?;
+        total_size
+            .checked_sub(::no_std_io::Cursor::get_index(&stream))
+            .ok_or(::no_std_io::Error::InvalidRead {
+                message: "Record is smaller than its fields",
+            })?
+    }}
+}
 
 fn create_field(
+    field_ident: &Ident,
     field: &Field,
     field_method: &proc_macro2::TokenStream,
+    struct_args: &StructArgs,
+    endian_flag: Option<&Ident>,
+    total_size: Option<&Ident>,
 ) -> proc_macro2::TokenStream {
-    let field_ident = field.ident.as_ref().expect("Field should have identity");
-    let pad_before = match MacroArgs::from_attributes(&field.attrs) {
-        Some(MacroArgs { pad_before }) => {
-            quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_before); }
+    let field_ty = &field.ty;
+    let args = MacroArgs::from_field(field);
+    if args.skip {
+        return quote! {
+            let #field_ident: #field_ty = ::core::default::Default::default();
+        };
+    }
+
+    let pad_before = match args.pad_before {
+        0 => quote! {},
+        pad_before => quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_before); },
+    };
+    let pad_after = match args.pad_after {
+        0 => quote! {},
+        pad_after => quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_after); },
+    };
+    let align = match struct_args.repr_c {
+        true => create_align(quote! { ::core::mem::align_of::<#field_ty>() }),
+        false => quote! {},
+    };
+
+    let field_method = args.field_method(
+        field_method,
+        quote! { read_stream_le },
+        quote! { read_stream_be },
+    );
+    let read = match (args.rest, args.sized_by()) {
+        (true, _) => {
+            let remaining_size = create_remaining_size(
+                total_size.expect("A rest field needs a total_size field before it"),
+            );
+            quote! {{
+                let size = #remaining_size;
+                ::no_std_io::StreamReader::read_byte_stream(&mut stream, size)?
+            }}
         }
-        _ => quote! {},
+        (false, Some(sized_by)) => quote! {{
+            let size: usize = ::core::convert::TryFrom::try_from(#sized_by).map_err(|_| {
+                ::no_std_io::Error::InvalidRead {
+                    message: "Invalid size",
+                }
+            })?;
+            ::no_std_io::StreamReader::read_byte_stream(&mut stream, size)?
+        }},
+        (false, None) => match endian_flag {
+            Some(endian_flag) if !args.forces_endian() => quote! {
+                if ::no_std_io::ByteOrderMark::is_big_endian(&#endian_flag) {
+                    ::no_std_io::StreamReader::read_stream_be(&mut stream)?
+                } else {
+                    ::no_std_io::StreamReader::read_stream_le(&mut stream)?
+                }
+            },
+            _ => quote! { ::no_std_io::StreamReader::#field_method(&mut stream)? },
+        },
+    };
+    let read = match args.scale() {
+        Some((scale, raw)) => quote! {{
+            let value: #raw = #read;
+            (value as f64 / #scale as f64) as #field_ty
+        }},
+        None => read,
     };
 
     quote! {
         #pad_before
-        let #field_ident = ::no_std_io::StreamReader::#field_method(&mut stream)?;
+        #align
+        let #field_ident = #read;
+        #pad_after
     }
 }
 
 fn create_method_impl(
-    fields: &Punctuated<Field, Comma>,
+    fields: &Fields,
     impl_method: proc_macro2::TokenStream,
     field_method: proc_macro2::TokenStream,
+    struct_args: &StructArgs,
 ) -> proc_macro2::TokenStream {
+    let field_idents = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| create_field_ident(index, field))
+        .collect::<Vec<Ident>>();
+    let endian_from = struct_args.endian_from();
+    let endian_flag_index = endian_from.as_ref().map(|endian_from| {
+        field_idents
+            .iter()
+            .position(|field_ident| field_ident == endian_from)
+            .expect("endian_from should name a field of the struct")
+    });
+    let total_size_index = fields
+        .iter()
+        .position(|field| MacroArgs::from_field(field).total_size);
     let field_tokens = fields
         .iter()
-        .map(|field| create_field(field, &field_method))
+        .zip(field_idents.iter())
+        .enumerate()
+        .map(|(index, (field, field_ident))| {
+            let endian_flag = match endian_flag_index {
+                Some(flag_index) if index > flag_index => endian_from.as_ref(),
+                _ => None,
+            };
+            let total_size = match total_size_index {
+                Some(size_index) if index > size_index => Some(&field_idents[size_index]),
+                _ => None,
+            };
+            create_field(
+                field_ident,
+                field,
+                &field_method,
+                struct_args,
+                endian_flag,
+                total_size,
+            )
+        })
         .collect::<Vec<proc_macro2::TokenStream>>();
-    let field_idents = fields
-        .iter()
-        .map(|field| field.ident.as_ref().expect("Field should have identity"))
-        .collect::<Vec<&Ident>>();
+    let skip_rest = match total_size_index {
+        Some(size_index) => {
+            let remaining_size = create_remaining_size(&field_idents[size_index]);
+            quote! {
+                let remaining_size = #remaining_size;
+                ::no_std_io::StreamReader::try_increment_by(&mut stream, remaining_size)?;
+            }
+        }
+        None => quote! {},
+    };
+    let result = match fields {
+        Fields::Unnamed(_) => quote! { Self(#(#field_idents),*) },
+        _ => quote! { Self { #(#field_idents),* } },
+    };
+    let align = match struct_args.repr_c {
+        true => create_align(quote! { ::core::mem::align_of::<Self>() }),
+        false => quote! {},
+    };
+    let magic = match &struct_args.magic {
+        Some(magic) => {
+            let magic_len = magic.value().len();
+            quote! {
+                let magic: [u8; #magic_len] =
+                    ::no_std_io::StreamReader::read_stream_le(&mut stream)?;
+                if &magic != #magic {
+                    return Err(::no_std_io::Error::InvalidRead {
+                        message: "Invalid magic",
+                    });
+                }
+            }
+        }
+        None => quote! {},
+    };
 
     quote! {
         #[inline(always)]
         fn #impl_method(bytes: &[u8]) -> Result<::no_std_io::ReadOutput<Self>, ::no_std_io::Error> {
             let mut stream = ::no_std_io::StreamContainer::new(bytes);
+            #magic
             #(#field_tokens)*
-            let result = Self {
-                #(#field_idents),*
-            };
+            #skip_rest
+            #align
+            let result = #result;
             let bytes_read = ::no_std_io::Cursor::get_index(&stream);
 
             Ok(::no_std_io::ReadOutput::new(result, bytes_read))
@@ -54,27 +207,120 @@ fn create_method_impl(
     }
 }
 
+fn create_enum_method_impl(
+    repr: &Ident,
+    variants: &[(&Ident, proc_macro2::TokenStream)],
+    unknown: Option<&Ident>,
+    impl_method: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (variants, discriminants): (Vec<_>, Vec<_>) = variants.iter().cloned().unzip();
+    let fallback = match unknown {
+        Some(unknown) => quote! {
+            value => {
+                let rest = bytes[read_bytes..].to_vec();
+                return Ok(::no_std_io::ReadOutput::new(Self::#unknown(value, rest), bytes.len()));
+            }
+        },
+        None => quote! {
+            _ => {
+                return Err(::no_std_io::Error::InvalidRead {
+                    message: "Unknown enum discriminant",
+                })
+            }
+        },
+    };
+
+    quote! {
+        #[inline(always)]
+        fn #impl_method(bytes: &[u8]) -> Result<::no_std_io::ReadOutput<Self>, ::no_std_io::Error> {
+            let discriminant = <#repr as ::no_std_io::EndianRead>::#impl_method(bytes)?;
+            let read_bytes = discriminant.get_read_bytes();
+            let result = match discriminant.into_data() {
+                #(value if value == #discriminants => Self::#variants,)*
+                #fallback
+            };
+
+            Ok(::no_std_io::ReadOutput::new(result, read_bytes))
+        }
+    }
+}
+
+fn impl_endian_read_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    struct_args: &StructArgs,
+) -> TokenStream {
+    let repr = enum_repr(&input.attrs);
+    let unknown = struct_args.unknown();
+    let variants = enum_variants(data, unknown.as_ref());
+
+    let try_read_le =
+        create_enum_method_impl(&repr, &variants, unknown.as_ref(), quote! { try_read_le });
+    let try_read_be =
+        create_enum_method_impl(&repr, &variants, unknown.as_ref(), quote! { try_read_be });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let modified = quote! {
+        impl #impl_generics ::no_std_io::EndianRead for #name #ty_generics #where_clause {
+            #try_read_le
+            #try_read_be
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Reads the little endian discriminant at the offset without reading the variant,
+            /// so the variant can be checked before committing to a read.
+            #[inline(always)]
+            pub fn peek_discriminant_le<R: ::no_std_io::Reader + ?Sized>(
+                reader: &R,
+                offset: usize,
+            ) -> Result<#repr, ::no_std_io::Error> {
+                ::no_std_io::Reader::read_le::<#repr>(reader, offset)
+            }
+
+            /// Same as `peek_discriminant_le`, but reads a big endian discriminant.
+            #[inline(always)]
+            pub fn peek_discriminant_be<R: ::no_std_io::Reader + ?Sized>(
+                reader: &R,
+                offset: usize,
+            ) -> Result<#repr, ::no_std_io::Error> {
+                ::no_std_io::Reader::read_be::<#repr>(reader, offset)
+            }
+        }
+    };
+
+    modified.into()
+}
+
 pub fn impl_endian_read(tokens: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
+    let struct_args = match parse_attributes(&input) {
+        Ok(struct_args) => struct_args,
+        Err(error) => return error.write_errors().into(),
+    };
 
-    let named_fields = match input.data {
+    let fields = match &input.data {
         Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
+            fields: fields @ (Fields::Named(_) | Fields::Unnamed(_)),
             ..
-        }) => fields.named,
-        _ => panic!("Only structs can derive EndianRead"),
+        }) => fields.clone(),
+        Data::Enum(data) => return impl_endian_read_enum(&input, data, &struct_args),
+        _ => panic!("Only structs and enums can derive EndianRead"),
     };
 
     let try_read_le = create_method_impl(
-        &named_fields,
+        &fields,
         quote! { try_read_le },
         quote! { read_stream_le },
+        &struct_args,
     );
 
     let try_read_be = create_method_impl(
-        &named_fields,
+        &fields,
         quote! { try_read_be },
         quote! { read_stream_be },
+        &struct_args,
     );
 
     let name = input.ident;
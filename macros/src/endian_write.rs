@@ -1,89 +1,391 @@
-use super::macro_args::MacroArgs;
+use super::macro_args::{enum_repr, enum_variants, parse_attributes, MacroArgs, StructArgs};
 use proc_macro::TokenStream;
+use proc_macro2::Ident;
 use quote::quote;
 use syn::{
-    self, parse_macro_input, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput,
-    Field, Fields,
+    self, parse_macro_input, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Index, Member,
 };
 
-fn create_get_size_field(field: &Field) -> proc_macro2::TokenStream {
-    let field_ident = field.ident.as_ref().expect("Field should have identity");
-    let pad_before = match MacroArgs::from_attributes(&field.attrs) {
-        Some(MacroArgs { pad_before }) => pad_before,
-        _ => 0,
+fn create_get_size_align(align: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+      size += ::no_std_io::padding_to_align(size, #align);
+    }
+}
+
+fn create_write_align(align: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+      let align = #align;
+      let padding = ::no_std_io::padding_to_align(::no_std_io::Cursor::get_index(&stream), align);
+      ::no_std_io::StreamReader::try_increment_by(&mut stream, padding)?;
+    }
+}
+
+fn create_field_member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    }
+}
+
+fn create_get_size_field(
+    field_member: &Member,
+    field: &Field,
+    struct_args: &StructArgs,
+) -> proc_macro2::TokenStream {
+    let field_ty = &field.ty;
+    let args = MacroArgs::from_field(field);
+    if args.skip {
+        return quote! {};
+    }
+
+    let pad_before = args.pad_before;
+    let pad_after = args.pad_after;
+    let value = args.write_value(field_member, field_ty);
+    let field_size = match (args.is_byte_field(), args.total_size || args.size_field) {
+        (true, _) => quote! { self.#field_member.len() },
+        // The size of a total_size or size_field field can't depend on its value,
+        // since the value is usually computed from get_size or the bytes written.
+        (false, true) => quote! { ::core::mem::size_of::<#field_ty>() },
+        (false, false) => quote! { ::no_std_io::EndianWrite::get_size(#value) },
+    };
+    let align = match struct_args.repr_c {
+        true => create_get_size_align(quote! { ::core::mem::align_of::<#field_ty>() }),
+        false => quote! {},
     };
 
     quote! {
       size += #pad_before;
-      size += ::no_std_io::EndianWrite::get_size(&self.#field_ident);
+      #align
+      size += #field_size;
+      size += #pad_after;
     }
 }
 
 fn create_write_field(
+    field_member: &Member,
     field: &Field,
     field_method: &proc_macro2::TokenStream,
+    struct_args: &StructArgs,
+    endian_flag: Option<&Ident>,
 ) -> proc_macro2::TokenStream {
-    let field_ident = field.ident.as_ref().expect("Field should have identity");
-    let pad_before = match MacroArgs::from_attributes(&field.attrs) {
-        Some(MacroArgs { pad_before }) => {
-            quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_before); }
+    let field_ty = &field.ty;
+    let args = MacroArgs::from_field(field);
+    if args.skip {
+        return quote! {};
+    }
+
+    let pad_before = match args.pad_before {
+        0 => quote! {},
+        pad_before => quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_before); },
+    };
+    let pad_after = match args.pad_after {
+        0 => quote! {},
+        pad_after => quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_after); },
+    };
+    let align = match struct_args.repr_c {
+        true => create_write_align(quote! { ::core::mem::align_of::<#field_ty>() }),
+        false => quote! {},
+    };
+
+    let write = match args.size_field {
+        true => {
+            let placeholder = quote! { &<#field_ty as ::core::default::Default>::default() };
+            let write =
+                create_write_value(field_member, &args, placeholder, field_method, endian_flag);
+            quote! {
+              let size_field_index = ::no_std_io::Cursor::get_index(&stream);
+              #write
+            }
+        }
+        false => {
+            let value = args.write_value(field_member, field_ty);
+            create_write_value(field_member, &args, value, field_method, endian_flag)
         }
-        _ => quote! {},
     };
 
     quote! {
       #pad_before
-      ::no_std_io::StreamWriter::#field_method(&mut stream, &self.#field_ident)?;
+      #align
+      #write
+      #pad_after
+    }
+}
+
+fn create_write_value(
+    field_member: &Member,
+    args: &MacroArgs,
+    value: proc_macro2::TokenStream,
+    field_method: &proc_macro2::TokenStream,
+    endian_flag: Option<&Ident>,
+) -> proc_macro2::TokenStream {
+    let field_method = args.field_method(
+        field_method,
+        quote! { write_stream_le },
+        quote! { write_stream_be },
+    );
+    match args.is_byte_field() {
+        true => quote! {
+          ::no_std_io::StreamWriter::write_stream_bytes(&mut stream, &self.#field_member)?;
+        },
+        false => match endian_flag {
+            Some(endian_flag) if !args.forces_endian() => quote! {
+              if ::no_std_io::ByteOrderMark::is_big_endian(&self.#endian_flag) {
+                ::no_std_io::StreamWriter::write_stream_be(&mut stream, #value)?;
+              } else {
+                ::no_std_io::StreamWriter::write_stream_le(&mut stream, #value)?;
+              }
+            },
+            _ => quote! {
+              ::no_std_io::StreamWriter::#field_method(&mut stream, #value)?;
+            },
+        },
+    }
+}
+
+fn create_size_field_patch(
+    field_member: &Member,
+    field: &Field,
+    field_method: &proc_macro2::TokenStream,
+    endian_flag: Option<&Ident>,
+) -> proc_macro2::TokenStream {
+    let field_ty = &field.ty;
+    let args = MacroArgs::from_field(field);
+    let write = create_write_value(
+        field_member,
+        &args,
+        quote! { &size },
+        field_method,
+        endian_flag,
+    );
+
+    quote! {
+      let size: #field_ty = ::core::convert::TryFrom::try_from(bytes_written).map_err(|_| {
+        ::no_std_io::Error::InvalidWrite {
+          message: "Size does not fit in size field",
+        }
+      })?;
+      ::no_std_io::Cursor::set_index(&mut stream, size_field_index);
+      #write
+      ::no_std_io::Cursor::set_index(&mut stream, bytes_written);
+    }
+}
+
+/// Returns an error if more than one field is marked `size_field`,
+/// or if a `size_field` is used with a `total_size` field, since both hold the record size.
+fn check_size_fields(fields: &Fields) -> darling::Result<()> {
+    let args = |field: &Field| MacroArgs::from_field(field);
+    let mut size_fields = fields.iter().filter(|field| args(field).size_field);
+
+    if let (Some(_), Some(duplicate)) = (size_fields.next(), size_fields.next()) {
+        return Err(
+            darling::Error::custom("Only one field can be marked size_field").with_span(duplicate),
+        );
+    }
+
+    match fields.iter().find(|field| args(field).total_size) {
+        Some(total_size) if fields.iter().any(|field| args(field).size_field) => Err(
+            darling::Error::custom("size_field and total_size can't be used on the same struct")
+                .with_span(total_size),
+        ),
+        _ => Ok(()),
     }
 }
 
 fn create_write_method_impl(
-    fields: &Punctuated<Field, Comma>,
+    fields: &Fields,
     impl_method: proc_macro2::TokenStream,
     field_method: proc_macro2::TokenStream,
+    struct_args: &StructArgs,
 ) -> proc_macro2::TokenStream {
+    let endian_from = struct_args.endian_from();
+    let endian_flag_index = endian_from.as_ref().map(|endian_from| {
+        fields
+            .iter()
+            .position(|field| field.ident.as_ref() == Some(endian_from))
+            .expect("endian_from should name a field of the struct")
+    });
+    let endian_flag = |index: usize| match endian_flag_index {
+        Some(flag_index) if index > flag_index => endian_from.as_ref(),
+        _ => None,
+    };
     let field_tokens = fields
         .iter()
-        .map(|field| create_write_field(field, &field_method))
+        .enumerate()
+        .map(|(index, field)| {
+            let field_member = create_field_member(index, field);
+            create_write_field(
+                &field_member,
+                field,
+                &field_method,
+                struct_args,
+                endian_flag(index),
+            )
+        })
         .collect::<Vec<proc_macro2::TokenStream>>();
+    let size_field_patch = fields
+        .iter()
+        .enumerate()
+        .find(|(_, field)| MacroArgs::from_field(field).size_field)
+        .map(|(index, field)| {
+            let field_member = create_field_member(index, field);
+            create_size_field_patch(&field_member, field, &field_method, endian_flag(index))
+        });
+    let align = match struct_args.repr_c {
+        true => create_write_align(quote! { ::core::mem::align_of::<Self>() }),
+        false => quote! {},
+    };
+
+    let magic = match &struct_args.magic {
+        Some(magic) => quote! {
+          ::no_std_io::StreamWriter::write_stream_bytes(&mut stream, #magic)?;
+        },
+        None => quote! {},
+    };
 
     quote! {
       #[inline(always)]
       fn #impl_method(&self, dst: &mut [u8]) -> Result<usize, ::no_std_io::Error> {
         let mut stream = ::no_std_io::StreamContainer::new(dst);
+        #magic
         #(#field_tokens)*
+        #align
         let bytes_written = ::no_std_io::Cursor::get_index(&stream);
+        #size_field_patch
         Ok(bytes_written)
       }
     }
 }
 
+fn create_enum_write_method_impl(
+    repr: &Ident,
+    variants: &[(&Ident, proc_macro2::TokenStream)],
+    unknown: Option<&Ident>,
+    impl_method: proc_macro2::TokenStream,
+    stream_method: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (variants, discriminants): (Vec<_>, Vec<_>) = variants.iter().cloned().unzip();
+    let unknown = unknown.map(|unknown| {
+        quote! {
+          Self::#unknown(discriminant, rest) => {
+            let mut stream = ::no_std_io::StreamContainer::new(dst);
+            ::no_std_io::StreamWriter::#stream_method(&mut stream, discriminant)?;
+            ::no_std_io::StreamWriter::write_stream_bytes(&mut stream, rest)?;
+            return Ok(::no_std_io::Cursor::get_index(&stream));
+          }
+        }
+    });
+
+    quote! {
+      #[inline(always)]
+      fn #impl_method(&self, dst: &mut [u8]) -> Result<usize, ::no_std_io::Error> {
+        let discriminant: #repr = match self {
+          #(Self::#variants => #discriminants,)*
+          #unknown
+        };
+        ::no_std_io::EndianWrite::#impl_method(&discriminant, dst)
+      }
+    }
+}
+
+fn impl_endian_write_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    struct_args: &StructArgs,
+) -> TokenStream {
+    let repr = enum_repr(&input.attrs);
+    let unknown = struct_args.unknown();
+    let variants = enum_variants(data, unknown.as_ref());
+
+    let try_write_le = create_enum_write_method_impl(
+        &repr,
+        &variants,
+        unknown.as_ref(),
+        quote! { try_write_le },
+        quote! { write_stream_le },
+    );
+    let try_write_be = create_enum_write_method_impl(
+        &repr,
+        &variants,
+        unknown.as_ref(),
+        quote! { try_write_be },
+        quote! { write_stream_be },
+    );
+    let get_size = match &unknown {
+        Some(unknown) => quote! {
+          match self {
+            Self::#unknown(_, rest) => ::core::mem::size_of::<#repr>() + rest.len(),
+            _ => ::core::mem::size_of::<#repr>(),
+          }
+        },
+        None => quote! { ::core::mem::size_of::<#repr>() },
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let modified = quote! {
+        impl #impl_generics ::no_std_io::EndianWrite for #name #ty_generics #where_clause {
+          fn get_size(&self) -> usize {
+            #get_size
+          }
+
+          #try_write_le
+          #try_write_be
+        }
+    };
+
+    modified.into()
+}
+
 pub fn impl_endian_write(tokens: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
+    let struct_args = match parse_attributes(&input) {
+        Ok(struct_args) => struct_args,
+        Err(error) => return error.write_errors().into(),
+    };
 
-    let named_fields = match input.data {
+    let fields = match &input.data {
         Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
+            fields: fields @ (Fields::Named(_) | Fields::Unnamed(_)),
             ..
-        }) => fields.named,
-        _ => panic!("Only structs can derive EndianWrite"),
+        }) => fields.clone(),
+        Data::Enum(data) => return impl_endian_write_enum(&input, data, &struct_args),
+        _ => panic!("Only structs and enums can derive EndianWrite"),
     };
 
-    let get_size_fields = named_fields
+    if let Err(error) = check_size_fields(&fields) {
+        return error.write_errors().into();
+    }
+
+    let get_size_fields = fields
         .iter()
-        .map(create_get_size_field)
+        .enumerate()
+        .map(|(index, field)| {
+            let field_member = create_field_member(index, field);
+            create_get_size_field(&field_member, field, &struct_args)
+        })
         .collect::<Vec<proc_macro2::TokenStream>>();
+    let get_size_align = match struct_args.repr_c {
+        true => create_get_size_align(quote! { ::core::mem::align_of::<Self>() }),
+        false => quote! {},
+    };
+    let magic_size = match &struct_args.magic {
+        Some(magic) => magic.value().len(),
+        None => 0,
+    };
 
     let try_write_le = create_write_method_impl(
-        &named_fields,
+        &fields,
         quote! { try_write_le },
         quote! { write_stream_le },
+        &struct_args,
     );
 
     let try_write_be = create_write_method_impl(
-        &named_fields,
+        &fields,
         quote! { try_write_be },
         quote! { write_stream_be },
+        &struct_args,
     );
 
     let name = input.ident;
@@ -92,8 +394,9 @@ pub fn impl_endian_write(tokens: TokenStream) -> TokenStream {
     let modified = quote! {
         impl #impl_generics ::no_std_io::EndianWrite for #name #ty_generics #where_clause {
           fn get_size(&self) -> usize {
-            let mut size = 0;
+            let mut size = #magic_size;
             #(#get_size_fields)*
+            #get_size_align
             size
           }
 
@@ -3,7 +3,19 @@ use proc_macro::TokenStream;
 mod endian_read;
 mod endian_write;
 mod macro_args;
+mod non_default_fields;
 
+/// Derives `EndianRead`, configured with one `#[no_std_io(...)]` attribute per struct or field.
+///
+/// Unknown or malformed arguments are compile errors instead of being ignored:
+///
+/// ```compile_fail
+/// #[derive(macros::EndianRead)]
+/// struct Padded {
+///     #[no_std_io(pad_befor = 2)]
+///     value: u8,
+/// }
+/// ```
 #[proc_macro_derive(EndianRead, attributes(no_std_io))]
 pub fn impl_endian_read(tokens: TokenStream) -> TokenStream {
     endian_read::impl_endian_read(tokens)
@@ -13,3 +25,8 @@ pub fn impl_endian_read(tokens: TokenStream) -> TokenStream {
 pub fn impl_endian_write(tokens: TokenStream) -> TokenStream {
     endian_write::impl_endian_write(tokens)
 }
+
+#[proc_macro_derive(NonDefaultFields)]
+pub fn impl_non_default_fields(tokens: TokenStream) -> TokenStream {
+    non_default_fields::impl_non_default_fields(tokens)
+}
@@ -1,19 +1,287 @@
 use darling::FromMeta;
-use syn::Attribute;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Expr, Field, Fields, LitByteStr, Member, Meta,
+    NestedMeta, Type,
+};
 
-#[derive(Debug, FromMeta)]
+fn from_attribute<T: FromMeta>(attr: &Attribute) -> darling::Result<Option<T>> {
+    if !attr.path.is_ident("no_std_io") {
+        return Ok(None);
+    }
+
+    let meta = attr.parse_meta()?;
+    T::from_meta(&meta)
+        .map(Some)
+        .map_err(|error| error.with_span(attr))
+}
+
+/// Parses the `no_std_io` attribute, returning an error if there is more than one.
+fn from_attributes<T: FromMeta>(attrs: &[Attribute]) -> darling::Result<Option<T>> {
+    let mut attrs = attrs.iter().filter(|attr| attr.path.is_ident("no_std_io"));
+
+    match (attrs.next(), attrs.next()) {
+        (_, Some(duplicate)) => Err(darling::Error::custom(
+            "Only one no_std_io attribute is allowed, so combine the arguments into one",
+        )
+        .with_span(duplicate)),
+        (Some(attr), None) => from_attribute(attr),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Parses the attributes of a derive input and its fields, returning the struct arguments.
+/// Every unknown or malformed argument is returned as one error, so the derives can report
+/// them before generating any code.
+pub fn parse_attributes(input: &DeriveInput) -> darling::Result<StructArgs> {
+    let mut errors = darling::Error::accumulator();
+    let struct_args = errors
+        .handle(StructArgs::from_attributes(&input.attrs))
+        .flatten()
+        .unwrap_or_default();
+
+    if let Data::Struct(data) = &input.data {
+        for field in data.fields.iter() {
+            errors.handle(MacroArgs::from_attributes(&field.attrs));
+        }
+    }
+
+    errors.finish_with(struct_args)
+}
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
 pub struct MacroArgs {
     pub pad_before: usize,
+    pub pad_after: usize,
+    /// Marks a nested struct field whose fields are serialized inline.
+    /// Nested fields are always read and written without any framing,
+    /// so this only documents that contract at the field and generates the same code.
+    #[allow(dead_code)]
+    pub flatten: bool,
+    /// Always reads and writes the field as little endian, even inside `try_read_be`/`try_write_be`.
+    /// Any `pad_before` padding is still skipped before the field, since padding has no endianness.
+    pub force_le: bool,
+    /// Same as `force_le`, but always uses big endian.
+    pub force_be: bool,
+    /// An expression used to compute the field's value on write instead of the stored value.
+    /// The field is read normally.
+    pub computed: Option<String>,
+    /// An expression using previously read fields that gives the number of bytes in a `Vec<u8>` field.
+    /// The field's bytes are read and written verbatim.
+    pub sized_by: Option<String>,
+    /// Marks an integer field holding the size of the whole record, including itself.
+    /// Any bytes left in the record after the last field are skipped on read.
+    /// Pair with `computed = "self.get_size() as u32"` (or another integer type) on write.
+    pub total_size: bool,
+    /// Marks a `Vec<u8>` field that captures the bytes left in the record after the known fields,
+    /// as given by the `total_size` field. The field's bytes are written verbatim.
+    pub rest: bool,
+    /// Marks an integer field that's written as a placeholder, then patched with the number of bytes
+    /// written for the whole record once the other fields are written.
+    /// The field is read normally.
+    /// Only one field can be marked, and it can't be used with a `total_size` field.
+    pub size_field: bool,
+    /// Stores a float field as an integer of the `raw` type, multiplied by the scale,
+    /// such as `scale = 100` for a value stored in hundredths.
+    /// The integer is divided by the scale on read.
+    /// On write, the scaled value is rounded to the nearest integer, with halfway values
+    /// rounded away from zero, and saturates at the bounds of the `raw` type.
+    pub scale: Option<u64>,
+    /// The integer type stored for a `scale` field, such as `"u16"`.
+    pub raw: Option<String>,
+    /// Leaves the field out of reads, writes, and sizes.
+    /// The field is set with `Default::default()` on read, so its type must implement `Default`.
+    pub skip: bool,
 }
 
 impl MacroArgs {
-    pub fn from_attribute(attr: &Attribute) -> Option<Self> {
-        attr.parse_meta()
+    pub fn from_attributes(attrs: &[Attribute]) -> darling::Result<Option<Self>> {
+        from_attributes(attrs)
+    }
+
+    /// Returns the field's arguments, or the defaults if it has none.
+    /// Errors are reported by [parse_attributes] before any code is generated.
+    pub fn from_field(field: &Field) -> Self {
+        Self::from_attributes(&field.attrs)
             .ok()
-            .and_then(|meta| MacroArgs::from_meta(&meta).ok())
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Returns the parsed `sized_by` expression, if any.
+    pub fn sized_by(&self) -> Option<Expr> {
+        self.sized_by.as_ref().map(|sized_by| {
+            syn::parse_str::<Expr>(sized_by).expect("sized_by should be a valid expression")
+        })
+    }
+
+    /// Returns the `scale` and the parsed `raw` type, if the field is scaled.
+    pub fn scale(&self) -> Option<(u64, Type)> {
+        self.scale.map(|scale| {
+            let raw = self.raw.as_ref().expect("A scale field needs a raw type");
+            let raw = syn::parse_str::<Type>(raw).expect("raw should be an integer type");
+            (scale, raw)
+        })
+    }
+
+    /// Returns the tokens for the value to write for a field,
+    /// which is either the computed expression, the scaled field, or the field itself.
+    pub fn write_value(&self, field_member: &Member, field_ty: &Type) -> proc_macro2::TokenStream {
+        match (&self.computed, self.scale()) {
+            (Some(computed), _) => {
+                let expr = syn::parse_str::<Expr>(computed)
+                    .expect("computed should be a valid expression");
+                quote! { &{ let value: #field_ty = #expr; value } }
+            }
+            (None, Some((scale, raw))) => quote! {
+                &{
+                    let scaled = self.#field_member as f64 * #scale as f64;
+                    let whole = scaled as i128 as f64;
+                    let fraction = scaled - whole;
+                    let rounded = if fraction >= 0.5 {
+                        whole + 1.0
+                    } else if fraction <= -0.5 {
+                        whole - 1.0
+                    } else {
+                        whole
+                    };
+                    let value: #raw = rounded as #raw;
+                    value
+                }
+            },
+            (None, None) => quote! { &self.#field_member },
+        }
     }
 
-    pub fn from_attributes(attrs: &[Attribute]) -> Option<Self> {
-        attrs.iter().find_map(Self::from_attribute)
+    /// Returns true if the field's bytes are read and written verbatim.
+    pub fn is_byte_field(&self) -> bool {
+        self.sized_by.is_some() || self.rest
     }
+
+    /// Returns true if the field always uses the same endianness.
+    pub fn forces_endian(&self) -> bool {
+        self.force_le || self.force_be
+    }
+
+    /// Returns the stream method to use for the field,
+    /// replacing the struct's method if the field forces an endianness.
+    pub fn field_method(
+        &self,
+        field_method: &proc_macro2::TokenStream,
+        le_method: proc_macro2::TokenStream,
+        be_method: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match (self.force_le, self.force_be) {
+            (true, true) => panic!("A field cannot force both little and big endian"),
+            (true, false) => le_method,
+            (false, true) => be_method,
+            (false, false) => field_method.clone(),
+        }
+    }
+}
+
+/// Arguments that apply to the whole struct rather than a single field.
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+pub struct StructArgs {
+    /// Skips padding before each field to reach the field type's natural alignment,
+    /// and after the last field to reach the struct's alignment, like a `#[repr(C)]` struct.
+    pub repr_c: bool,
+    /// A field implementing `ByteOrderMark` that decides the endianness of every field after it,
+    /// regardless of whether `try_read_le`/`try_read_be` was called.
+    pub endian_from: Option<String>,
+    /// A byte string, such as `b"NSIO"`, that's written before the first field
+    /// and must match on read.
+    pub magic: Option<LitByteStr>,
+    /// The name of a tuple variant, such as `Unknown(u16, Vec<u8>)`, that holds the discriminant
+    /// and the rest of the record's bytes when no other variant matches the discriminant.
+    /// The bytes are written back verbatim, so unknown variants round trip unchanged.
+    /// The rest of the record is every byte after the discriminant, so the enum must be read
+    /// from a length bounded record, such as a `sized_by` field or a slice of the record.
+    pub unknown: Option<String>,
+}
+
+impl StructArgs {
+    /// Returns the parsed `endian_from` field, if any.
+    pub fn endian_from(&self) -> Option<Ident> {
+        self.endian_from.as_ref().map(|endian_from| {
+            syn::parse_str::<Ident>(endian_from).expect("endian_from should be a field name")
+        })
+    }
+
+    /// Returns the parsed `unknown` variant, if any.
+    pub fn unknown(&self) -> Option<Ident> {
+        self.unknown.as_ref().map(|unknown| {
+            syn::parse_str::<Ident>(unknown).expect("unknown should be a variant name")
+        })
+    }
+
+    pub fn from_attributes(attrs: &[Attribute]) -> darling::Result<Option<Self>> {
+        from_attributes(attrs)
+    }
+}
+
+const REPR_TYPES: [&str; 8] = ["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"];
+
+/// Returns the integer type of an enum's `#[repr]` attribute,
+/// which is used to read and write the discriminant.
+pub fn enum_repr(attrs: &[Attribute]) -> Ident {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => path
+                .get_ident()
+                .filter(|ident| REPR_TYPES.iter().any(|repr| ident == repr))
+                .cloned(),
+            _ => None,
+        })
+        .expect("Enums must have an integer #[repr] to derive EndianRead or EndianWrite")
+}
+
+/// Returns the unit variants of an enum with the tokens for their discriminants,
+/// leaving out the `unknown` variant.
+///
+/// Discriminants without an explicit value are one more than the previous variant's,
+/// the same as the compiler assigns them.
+pub fn enum_variants<'a>(
+    data: &'a DataEnum,
+    unknown: Option<&Ident>,
+) -> Vec<(&'a Ident, proc_macro2::TokenStream)> {
+    if let Some(unknown) = unknown {
+        let variant = data
+            .variants
+            .iter()
+            .find(|variant| &variant.ident == unknown)
+            .expect("unknown should name a variant of the enum");
+        match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 2 => {}
+            _ => panic!("The unknown variant must hold the discriminant and a Vec<u8>"),
+        }
+    }
+
+    let mut discriminant = quote! { 0 };
+    data.variants
+        .iter()
+        .filter_map(|variant| {
+            if let Some((_, expr)) = &variant.discriminant {
+                discriminant = quote! { (#expr) };
+            }
+            let current = discriminant.clone();
+            discriminant = quote! { #current + 1 };
+
+            match variant.fields {
+                _ if Some(&variant.ident) == unknown => None,
+                Fields::Unit => Some((&variant.ident, current)),
+                _ => panic!("Only enums with unit variants can derive EndianRead or EndianWrite"),
+            }
+        })
+        .collect()
 }
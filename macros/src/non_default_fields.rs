@@ -0,0 +1,45 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Index, Member};
+
+pub fn impl_non_default_fields(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    let fields = match input.data {
+        Data::Struct(DataStruct { fields, .. }) => fields,
+        _ => panic!("Only structs can derive NonDefaultFields"),
+    };
+
+    let field_checks = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (field_member, field_name) = match &field.ident {
+                Some(ident) => (Member::Named(ident.clone()), ident.to_string()),
+                None => (Member::Unnamed(Index::from(index)), index.to_string()),
+            };
+
+            quote! {
+                if self.#field_member != default.#field_member {
+                    fields.push(#field_name);
+                }
+            }
+        })
+        .collect::<Vec<proc_macro2::TokenStream>>();
+
+    let name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let modified = quote! {
+        impl #impl_generics ::no_std_io::NonDefaultFields for #name #ty_generics #where_clause {
+            fn non_default_fields(&self) -> ::no_std_io::FieldNames {
+                let default = <Self as ::core::default::Default>::default();
+                let mut fields = ::no_std_io::FieldNames::new();
+                #(#field_checks)*
+                fields
+            }
+        }
+    };
+
+    modified.into()
+}
@@ -162,6 +162,24 @@ fn should_read_nested_be() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn should_read_arrays_of_derived_structs() {
+    let bytes = [0xaa, 0x11, 0x22, 0x33, 0x44, 0xbb, 0x55, 0x66, 0x77, 0x88];
+    let result: [Test; 2] = bytes.read_be(0).expect("Read should have worked");
+    let expected = [
+        Test {
+            first: 0xaa,
+            second: 0x11223344,
+        },
+        Test {
+            first: 0xbb,
+            second: 0x55667788,
+        },
+    ];
+
+    assert_eq!(result, expected);
+}
+
 mod padding {
     use super::*;
 
@@ -280,3 +298,679 @@ mod padding {
         assert_eq!(result, expected);
     }
 }
+
+mod flatten {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Header {
+        magic: u16,
+        version: u8,
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Flattened {
+        #[no_std_io(flatten)]
+        header: Header,
+        body: u32,
+    }
+
+    #[test]
+    fn should_read_fields_inline_le() {
+        let bytes = vec![0xbb, 0xaa, 0x01, 0x44, 0x33, 0x22, 0x11];
+        let result = bytes
+            .read_le_with_output::<Flattened>(0)
+            .expect("Read should have worked");
+        let expected = Flattened {
+            header: Header {
+                magic: 0xaabb,
+                version: 0x01,
+            },
+            body: 0x11223344,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 7));
+    }
+}
+
+mod repr_c {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[repr(C)]
+    #[no_std_io(repr_c)]
+    struct CStruct {
+        first: u8,
+        second: u32,
+        third: u16,
+    }
+
+    #[test]
+    fn should_skip_alignment_padding_le() {
+        let bytes = vec![
+            0xaa, 0xff, 0xff, 0xff, 0x44, 0x33, 0x22, 0x11, 0xcc, 0xbb, 0xff, 0xff,
+        ];
+        let result = bytes
+            .read_le_with_output::<CStruct>(0)
+            .expect("Read should have worked");
+        let expected = CStruct {
+            first: 0xaa,
+            second: 0x11223344,
+            third: 0xbbcc,
+        };
+
+        assert_eq!(
+            result,
+            ReadOutput::new(expected, core::mem::size_of::<CStruct>())
+        );
+    }
+
+    #[test]
+    fn should_skip_alignment_padding_be() {
+        let bytes = vec![
+            0xaa, 0xff, 0xff, 0xff, 0x11, 0x22, 0x33, 0x44, 0xbb, 0xcc, 0xff, 0xff,
+        ];
+        let result: CStruct = bytes.read_be(0).expect("Read should have worked");
+        let expected = CStruct {
+            first: 0xaa,
+            second: 0x11223344,
+            third: 0xbbcc,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_error_if_trailing_padding_is_missing() {
+        let bytes = vec![0xaa, 0xff, 0xff, 0xff, 0x44, 0x33, 0x22, 0x11, 0xcc, 0xbb];
+        let result = bytes
+            .read_le::<CStruct>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            result,
+            Error::InvalidSize {
+                wanted_size: 2,
+                offset: 10,
+                data_len: 10
+            }
+        );
+    }
+}
+
+mod tuple_struct {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Pair(u8, u32);
+
+    #[test]
+    fn should_read_le() {
+        let bytes = vec![0xaa, 0xbb, 0xcc, 0xdd, 0xee];
+        let result = bytes
+            .read_le_with_output::<Pair>(0)
+            .expect("Read should have worked");
+
+        assert_eq!(result, ReadOutput::new(Pair(0xaa, 0xeeddccbb), 5));
+    }
+
+    #[test]
+    fn should_read_be() {
+        let bytes = vec![0xaa, 0xbb, 0xcc, 0xdd, 0xee];
+        let result: Pair = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(result, Pair(0xaa, 0xbbccddee));
+    }
+}
+
+mod force_endian {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct MixedEndian {
+        length: u16,
+        #[no_std_io(pad_before = 1, force_be)]
+        port: u16,
+        #[no_std_io(force_le)]
+        checksum: u16,
+    }
+
+    #[test]
+    fn should_read_forced_fields_in_le() {
+        let bytes = vec![0x11, 0x22, 0x00, 0xaa, 0xbb, 0xcc, 0xdd];
+        let result: MixedEndian = bytes.read_le(0).expect("Read should have worked");
+        let expected = MixedEndian {
+            length: 0x2211,
+            port: 0xaabb,
+            checksum: 0xddcc,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_read_forced_fields_in_be() {
+        let bytes = vec![0x11, 0x22, 0x00, 0xaa, 0xbb, 0xcc, 0xdd];
+        let result: MixedEndian = bytes.read_be(0).expect("Read should have worked");
+        let expected = MixedEndian {
+            length: 0x1122,
+            port: 0xaabb,
+            checksum: 0xddcc,
+        };
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod pad_after {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct PaddedAfter {
+        #[no_std_io(pad_after = 3)]
+        first: u8,
+        second: u8,
+    }
+
+    #[test]
+    fn should_skip_bytes_after_the_field() {
+        let bytes = vec![0xaa, 0xff, 0xff, 0xff, 0xbb];
+        let result = bytes
+            .read_le_with_output::<PaddedAfter>(0)
+            .expect("Read should have worked");
+        let expected = PaddedAfter {
+            first: 0xaa,
+            second: 0xbb,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 5));
+    }
+}
+
+mod computed {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Computed {
+        #[no_std_io(computed = "self.items.0.len() as u8")]
+        count: u8,
+        items: ListContainer<u8>,
+    }
+
+    #[test]
+    fn should_read_computed_fields_normally() {
+        let bytes = vec![0x07, 0x02, 0xaa, 0xbb];
+        let result: Computed = bytes.read_le(0).expect("Read should have worked");
+        let expected = Computed {
+            count: 0x07,
+            items: ListContainer(vec![0xaa, 0xbb]),
+        };
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod enums {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[repr(u16)]
+    enum PacketType {
+        Ping = 0x0001,
+        Data = 0x0100,
+        Close = 0xff00,
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[repr(u8)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    #[test]
+    fn should_read_discriminant_le() {
+        let bytes = vec![0x00, 0x01, 0x01];
+        let result = bytes
+            .read_le_with_output::<PacketType>(0)
+            .expect("Read should have worked");
+
+        assert_eq!(result, ReadOutput::new(PacketType::Data, 2));
+        assert_eq!(bytes.read_le::<Flag>(2), Ok(Flag::On));
+    }
+
+    #[test]
+    fn should_read_discriminant_be() {
+        let bytes = vec![0xff, 0x00];
+        let result: PacketType = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(result, PacketType::Close);
+    }
+
+    #[test]
+    fn should_return_error_for_unknown_discriminant() {
+        let bytes = vec![0x02, 0x00];
+        let result = bytes
+            .read_le::<PacketType>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            result,
+            Error::InvalidRead {
+                message: "Unknown enum discriminant"
+            }
+        );
+        assert!(bytes.read_le::<Flag>(0).is_err());
+        assert_eq!(bytes.read_le::<Flag>(1), Ok(Flag::Off));
+    }
+
+    #[test]
+    fn should_peek_discriminant_without_advancing() {
+        let bytes = [0xaa, 0x00, 0x01, 0x01];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        stream.set_index(1);
+
+        assert_eq!(PacketType::peek_discriminant_le(&stream, 1), Ok(0x0100));
+        assert_eq!(PacketType::peek_discriminant_be(&stream, 1), Ok(0x0001));
+        assert_eq!(stream.get_index(), 1);
+    }
+
+    #[test]
+    fn should_peek_unknown_discriminant() {
+        let bytes = [0x02];
+
+        assert_eq!(Flag::peek_discriminant_le(&bytes, 0), Ok(2));
+        assert!(bytes.read_le::<Flag>(0).is_err());
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[repr(u16)]
+    #[no_std_io(unknown = "Unknown")]
+    enum Record {
+        Ping = 1,
+        Pong,
+        Unknown(u16, Vec<u8>),
+    }
+
+    #[test]
+    fn should_read_known_discriminant_with_unknown_variant() {
+        let bytes = vec![0x02, 0x00, 0xaa];
+        let result = bytes
+            .read_le_with_output::<Record>(0)
+            .expect("Read should have worked");
+
+        assert_eq!(result, ReadOutput::new(Record::Pong, 2));
+        assert_eq!(
+            bytes.read_le::<Record>(1),
+            Ok(Record::Unknown(0xaa00, vec![]))
+        );
+    }
+
+    #[test]
+    fn should_preserve_unknown_discriminant_and_bytes() {
+        let bytes = vec![0x00, 0x07, 0xaa, 0xbb];
+        let result = bytes
+            .read_be_with_output::<Record>(0)
+            .expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            ReadOutput::new(Record::Unknown(0x07, vec![0xaa, 0xbb]), 4)
+        );
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Packet {
+        packet_type: PacketType,
+        length: u32,
+    }
+
+    #[test]
+    fn should_read_enum_fields() {
+        let bytes = vec![0x01, 0x00, 0x04, 0x00, 0x00, 0x00];
+        let result: Packet = bytes.read_le(0).expect("Read should have worked");
+        let expected = Packet {
+            packet_type: PacketType::Ping,
+            length: 4,
+        };
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod sized_by {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Blob {
+        size: u32,
+        #[no_std_io(sized_by = "size")]
+        payload: Vec<u8>,
+        trailer: u8,
+    }
+
+    #[test]
+    fn should_read_payload_of_size_le() {
+        let bytes = vec![0x03, 0x00, 0x00, 0x00, 0xaa, 0xbb, 0xcc, 0xff];
+        let result = bytes
+            .read_le_with_output::<Blob>(0)
+            .expect("Read should have worked");
+        let expected = Blob {
+            size: 3,
+            payload: vec![0xaa, 0xbb, 0xcc],
+            trailer: 0xff,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 8));
+    }
+
+    #[test]
+    fn should_read_payload_of_size_be() {
+        let bytes = vec![0x00, 0x00, 0x00, 0x00, 0xff];
+        let result: Blob = bytes.read_be(0).expect("Read should have worked");
+        let expected = Blob {
+            size: 0,
+            payload: vec![],
+            trailer: 0xff,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_return_error_if_payload_is_too_large() {
+        let bytes = vec![0x08, 0x00, 0x00, 0x00, 0xaa, 0xbb];
+        let result = bytes
+            .read_le::<Blob>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            result,
+            Error::InvalidSize {
+                wanted_size: 8,
+                offset: 4,
+                data_len: 6
+            }
+        );
+    }
+}
+
+mod endian_from {
+    use super::*;
+    use no_std_io::ByteOrderMark;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[repr(u16)]
+    enum ByteOrder {
+        Little = 0x4949,
+        Big = 0x4d4d,
+    }
+
+    impl ByteOrderMark for ByteOrder {
+        fn is_big_endian(&self) -> bool {
+            *self == ByteOrder::Big
+        }
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[no_std_io(endian_from = "byte_order")]
+    struct TiffHeader {
+        byte_order: ByteOrder,
+        magic: u16,
+        #[no_std_io(force_le)]
+        forced: u16,
+        first_offset: u32,
+    }
+
+    #[test]
+    fn should_read_little_endian_after_the_mark() {
+        let bytes = vec![0x49, 0x49, 0x2a, 0x00, 0x11, 0x22, 0x08, 0x00, 0x00, 0x00];
+        let result = bytes
+            .read_be_with_output::<TiffHeader>(0)
+            .expect("Read should have worked");
+        let expected = TiffHeader {
+            byte_order: ByteOrder::Little,
+            magic: 42,
+            forced: 0x2211,
+            first_offset: 8,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 10));
+    }
+
+    #[test]
+    fn should_read_big_endian_after_the_mark() {
+        let bytes = vec![0x4d, 0x4d, 0x00, 0x2a, 0x11, 0x22, 0x00, 0x00, 0x00, 0x08];
+        let result: TiffHeader = bytes.read_le(0).expect("Read should have worked");
+        let expected = TiffHeader {
+            byte_order: ByteOrder::Big,
+            magic: 42,
+            forced: 0x2211,
+            first_offset: 8,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[no_std_io(endian_from = "big_endian")]
+    struct Flagged {
+        length: u16,
+        big_endian: bool,
+        value: u16,
+    }
+
+    #[test]
+    fn should_use_the_called_endianness_before_the_mark() {
+        let bytes = vec![0x01, 0x00, 0x01, 0xaa, 0xbb];
+        let result: Flagged = bytes.read_le(0).expect("Read should have worked");
+        let expected = Flagged {
+            length: 1,
+            big_endian: true,
+            value: 0xaabb,
+        };
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod total_size {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Record {
+        #[no_std_io(total_size)]
+        size: u16,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct RecordWithRest {
+        #[no_std_io(total_size)]
+        size: u16,
+        value: u16,
+        #[no_std_io(rest)]
+        extra: Vec<u8>,
+    }
+
+    #[test]
+    fn should_skip_unknown_trailing_fields() {
+        let bytes = vec![0x06, 0x00, 0x11, 0x22, 0xaa, 0xbb, 0xff];
+        let result = bytes
+            .read_le_with_output::<Record>(0)
+            .expect("Read should have worked");
+        let expected = Record {
+            size: 6,
+            value: 0x2211,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 6));
+    }
+
+    #[test]
+    fn should_capture_unknown_trailing_fields() {
+        let bytes = vec![0x00, 0x06, 0x11, 0x22, 0xaa, 0xbb, 0xff];
+        let result = bytes
+            .read_be_with_output::<RecordWithRest>(0)
+            .expect("Read should have worked");
+        let expected = RecordWithRest {
+            size: 6,
+            value: 0x1122,
+            extra: vec![0xaa, 0xbb],
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 6));
+    }
+
+    #[test]
+    fn should_read_records_without_trailing_fields() {
+        let bytes = vec![0x04, 0x00, 0x11, 0x22];
+        let result: RecordWithRest = bytes.read_le(0).expect("Read should have worked");
+        let expected = RecordWithRest {
+            size: 4,
+            value: 0x2211,
+            extra: vec![],
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_return_error_if_size_is_smaller_than_the_fields() {
+        let bytes = vec![0x03, 0x00, 0x11, 0x22];
+        let result = bytes.read_le::<Record>(0);
+
+        assert_eq!(
+            result,
+            Err(Error::InvalidRead {
+                message: "Record is smaller than its fields",
+            })
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_size_is_larger_than_the_data() {
+        let bytes = vec![0x08, 0x00, 0x11, 0x22, 0xaa];
+        let result = bytes.read_le::<Record>(0);
+
+        assert!(matches!(result, Err(Error::InvalidSize { .. })));
+    }
+}
+
+mod skip {
+    use super::*;
+    use core::marker::PhantomData;
+    use no_std_io::Writer;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Cache(u32);
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    struct Skipped {
+        first: u8,
+        #[no_std_io(skip)]
+        cache: Cache,
+        #[no_std_io(skip)]
+        marker: PhantomData<u64>,
+        second: u16,
+    }
+
+    #[test]
+    fn should_fill_skipped_fields_with_default() {
+        let bytes = vec![0xaa, 0x11, 0x22, 0xff];
+        let result = bytes
+            .read_le_with_output::<Skipped>(0)
+            .expect("Read should have worked");
+        let expected = Skipped {
+            first: 0xaa,
+            cache: Cache(0),
+            marker: PhantomData,
+            second: 0x2211,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 3));
+    }
+
+    #[test]
+    fn should_round_trip_without_skipped_fields() {
+        let value = Skipped {
+            first: 0xaa,
+            cache: Cache(0),
+            marker: PhantomData,
+            second: 0x1122,
+        };
+        let mut bytes = vec![];
+        bytes.write_be(0, &value).expect("Write should have worked");
+        let result: Skipped = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(bytes, [0xaa, 0x11, 0x22]);
+        assert_eq!(result, value);
+    }
+}
+
+mod magic {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    #[no_std_io(magic = b"NSIO")]
+    struct Header {
+        version: u16,
+    }
+
+    #[test]
+    fn should_read_after_the_magic() {
+        let bytes = vec![b'N', b'S', b'I', b'O', 0x01, 0x00];
+        let result = bytes
+            .read_le_with_output::<Header>(0)
+            .expect("Read should have worked");
+
+        assert_eq!(result, ReadOutput::new(Header { version: 1 }, 6));
+    }
+
+    #[test]
+    fn should_return_error_for_incorrect_magic() {
+        let bytes = vec![b'N', b'S', b'I', b'X', 0x01, 0x00];
+        let result = bytes.read_le::<Header>(0);
+
+        assert_eq!(
+            result,
+            Err(Error::InvalidRead {
+                message: "Invalid magic",
+            })
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_magic_is_too_short() {
+        let bytes = vec![b'N', b'S'];
+        let result = bytes.read_le::<Header>(0);
+
+        assert!(matches!(result, Err(Error::InvalidSize { .. })));
+    }
+}
+
+mod scale {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead)]
+    struct Sensor {
+        #[no_std_io(scale = 100, raw = "u16")]
+        temperature: f32,
+        #[no_std_io(scale = 10, raw = "i8")]
+        offset: f64,
+    }
+
+    #[test]
+    fn should_divide_by_the_scale() {
+        let bytes = vec![0x96, 0x00, 0xf1];
+        let result = bytes
+            .read_le_with_output::<Sensor>(0)
+            .expect("Read should have worked");
+        let expected = Sensor {
+            temperature: 1.5,
+            offset: -1.5,
+        };
+
+        assert_eq!(result, ReadOutput::new(expected, 3));
+    }
+}
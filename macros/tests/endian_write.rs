@@ -337,3 +337,709 @@ mod padding {
         );
     }
 }
+
+mod flatten {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    struct Header {
+        magic: u16,
+        version: u8,
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    struct Flattened {
+        #[no_std_io(flatten)]
+        header: Header,
+        body: u32,
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    struct Inlined {
+        magic: u16,
+        version: u8,
+        body: u32,
+    }
+
+    #[test]
+    fn should_write_identical_bytes_to_inlined_fields_le() {
+        let flattened = Flattened {
+            header: Header {
+                magic: 0xaabb,
+                version: 0x01,
+            },
+            body: 0x11223344,
+        };
+        let inlined = Inlined {
+            magic: 0xaabb,
+            version: 0x01,
+            body: 0x11223344,
+        };
+
+        let mut flattened_bytes = vec![];
+        let mut inlined_bytes = vec![];
+        flattened_bytes
+            .write_le(0, &flattened)
+            .expect("Write should have worked");
+        inlined_bytes
+            .write_le(0, &inlined)
+            .expect("Write should have worked");
+
+        assert_eq!(flattened_bytes, inlined_bytes);
+        assert_eq!(flattened_bytes, [0xbb, 0xaa, 0x01, 0x44, 0x33, 0x22, 0x11]);
+    }
+
+    #[test]
+    fn should_write_identical_bytes_to_inlined_fields_be() {
+        let flattened = Flattened {
+            header: Header {
+                magic: 0xaabb,
+                version: 0x01,
+            },
+            body: 0x11223344,
+        };
+        let inlined = Inlined {
+            magic: 0xaabb,
+            version: 0x01,
+            body: 0x11223344,
+        };
+
+        let mut flattened_bytes = vec![];
+        let mut inlined_bytes = vec![];
+        flattened_bytes
+            .write_be(0, &flattened)
+            .expect("Write should have worked");
+        inlined_bytes
+            .write_be(0, &inlined)
+            .expect("Write should have worked");
+
+        assert_eq!(flattened_bytes, inlined_bytes);
+        assert_eq!(flattened_bytes, [0xaa, 0xbb, 0x01, 0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn should_get_the_same_size_as_inlined_fields() {
+        let flattened = Flattened {
+            header: Header {
+                magic: 0xaabb,
+                version: 0x01,
+            },
+            body: 0x11223344,
+        };
+
+        assert_eq!(no_std_io::EndianWrite::get_size(&flattened), 7);
+    }
+}
+
+mod repr_c {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    #[repr(C)]
+    #[no_std_io(repr_c)]
+    struct CStruct {
+        first: u8,
+        second: u32,
+        third: u16,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = CStruct {
+            first: 0xaa,
+            second: 0x11223344,
+            third: 0xbbcc,
+        };
+        assert_eq!(value.get_size(), core::mem::size_of::<CStruct>());
+    }
+
+    #[test]
+    fn should_insert_alignment_padding_le() {
+        let value = CStruct {
+            first: 0xaa,
+            second: 0x11223344,
+            third: 0xbbcc,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 12);
+        assert_eq!(
+            bytes,
+            [0xaa, 0x00, 0x00, 0x00, 0x44, 0x33, 0x22, 0x11, 0xcc, 0xbb, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn should_insert_alignment_padding_be() {
+        let value = CStruct {
+            first: 0xaa,
+            second: 0x11223344,
+            third: 0xbbcc,
+        };
+        let mut bytes = vec![0; 12];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 12);
+        assert_eq!(
+            bytes,
+            [0xaa, 0x00, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0xbb, 0xcc, 0x00, 0x00]
+        );
+    }
+}
+
+mod tuple_struct {
+    use super::*;
+    use no_std_io::{EndianWrite, Reader};
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    struct Pair(u8, u32);
+
+    #[test]
+    fn should_get_size() {
+        assert_eq!(Pair(0xaa, 0xeeddccbb).get_size(), 5);
+    }
+
+    #[test]
+    fn should_write_le() {
+        let mut bytes = vec![];
+        let result = bytes
+            .write_le(0, &Pair(0xaa, 0xeeddccbb))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 5);
+        assert_eq!(bytes, [0xaa, 0xbb, 0xcc, 0xdd, 0xee]);
+    }
+
+    #[test]
+    fn should_round_trip_be() {
+        let value = Pair(0xaa, 0xbbccddee);
+        let mut bytes = vec![0; 5];
+        bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0xaa, 0xbb, 0xcc, 0xdd, 0xee]);
+        assert_eq!(bytes.read_be::<Pair>(0), Ok(value));
+    }
+}
+
+mod force_endian {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct MixedEndian {
+        length: u16,
+        #[no_std_io(pad_before = 1, force_be)]
+        port: u16,
+        #[no_std_io(force_le)]
+        checksum: u16,
+    }
+
+    #[test]
+    fn should_write_forced_fields_in_le() {
+        let value = MixedEndian {
+            length: 0x2211,
+            port: 0xaabb,
+            checksum: 0xddcc,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 7);
+        assert_eq!(bytes, [0x11, 0x22, 0x00, 0xaa, 0xbb, 0xcc, 0xdd]);
+    }
+
+    #[test]
+    fn should_write_forced_fields_in_be() {
+        let value = MixedEndian {
+            length: 0x1122,
+            port: 0xaabb,
+            checksum: 0xddcc,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 7);
+        assert_eq!(bytes, [0x11, 0x22, 0x00, 0xaa, 0xbb, 0xcc, 0xdd]);
+    }
+}
+
+mod pad_after {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct PaddedAfter {
+        #[no_std_io(pad_after = 3)]
+        first: u8,
+        second: u8,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = PaddedAfter {
+            first: 0xaa,
+            second: 0xbb,
+        };
+        assert_eq!(value.get_size(), 5);
+    }
+
+    #[test]
+    fn should_write_padding_after_the_field() {
+        let value = PaddedAfter {
+            first: 0xaa,
+            second: 0xbb,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 5);
+        assert_eq!(bytes, [0xaa, 0x00, 0x00, 0x00, 0xbb]);
+    }
+}
+
+mod computed {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct Computed {
+        #[no_std_io(computed = "self.items.0.len() as u8")]
+        count: u8,
+        #[no_std_io(computed = "self.items.0.iter().map(|item| *item as u16).sum()")]
+        checksum: u16,
+        items: ListContainer<u8>,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = Computed {
+            count: 0,
+            checksum: 0,
+            items: ListContainer(vec![0xaa, 0xbb]),
+        };
+        assert_eq!(value.get_size(), 6);
+    }
+
+    #[test]
+    fn should_write_computed_values() {
+        let value = Computed {
+            count: 7,
+            checksum: 0,
+            items: ListContainer(vec![0xaa, 0xbb]),
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 6);
+        assert_eq!(bytes, [0x02, 0x65, 0x01, 0x02, 0xaa, 0xbb]);
+    }
+}
+
+mod enums {
+    use super::*;
+    use no_std_io::{EndianWrite, Reader};
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    #[repr(u32)]
+    enum PacketType {
+        Ping = 1,
+        Close = 0xaabbccdd,
+    }
+
+    #[test]
+    fn should_get_size() {
+        assert_eq!(PacketType::Ping.get_size(), 4);
+    }
+
+    #[test]
+    fn should_write_discriminant_le() {
+        let mut bytes = vec![];
+        let result = bytes
+            .write_le(0, &PacketType::Close)
+            .expect("Write should have worked");
+
+        assert_eq!(result, 4);
+        assert_eq!(bytes, [0xdd, 0xcc, 0xbb, 0xaa]);
+    }
+
+    #[test]
+    fn should_write_discriminant_be() {
+        let mut bytes = vec![];
+        let result = bytes
+            .write_be(0, &PacketType::Ping)
+            .expect("Write should have worked");
+
+        assert_eq!(result, 4);
+        assert_eq!(bytes, [0x00, 0x00, 0x00, 0x01]);
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    #[repr(u16)]
+    #[no_std_io(unknown = "Unknown")]
+    enum Record {
+        Ping = 1,
+        Pong,
+        Unknown(u16, Vec<u8>),
+    }
+
+    #[test]
+    fn should_write_known_discriminant_with_unknown_variant() {
+        let mut bytes = vec![];
+        let result = bytes
+            .write_le(0, &Record::Pong)
+            .expect("Write should have worked");
+
+        assert_eq!(Record::Pong.get_size(), 2);
+        assert_eq!(result, 2);
+        assert_eq!(bytes, [0x02, 0x00]);
+    }
+
+    #[test]
+    fn should_round_trip_unknown_variant() {
+        let value = Record::Unknown(0x07, vec![0xaa, 0xbb]);
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(value.get_size(), 4);
+        assert_eq!(result, 4);
+        assert_eq!(bytes, [0x00, 0x07, 0xaa, 0xbb]);
+        assert_eq!(bytes.read_be::<Record>(0), Ok(value));
+    }
+}
+
+mod sized_by {
+    use super::*;
+    use no_std_io::{EndianWrite, Reader};
+
+    #[derive(Debug, PartialEq, no_std_io::EndianRead, no_std_io::EndianWrite)]
+    struct Blob {
+        #[no_std_io(computed = "self.payload.len() as u16")]
+        size: u16,
+        #[no_std_io(sized_by = "size")]
+        payload: Vec<u8>,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = Blob {
+            size: 0,
+            payload: vec![0xaa, 0xbb, 0xcc],
+        };
+        assert_eq!(value.get_size(), 5);
+    }
+
+    #[test]
+    fn should_write_size_and_payload() {
+        let value = Blob {
+            size: 0,
+            payload: vec![0xaa, 0xbb, 0xcc],
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 5);
+        assert_eq!(bytes, [0x00, 0x03, 0xaa, 0xbb, 0xcc]);
+
+        let read: Blob = bytes.read_be(0).expect("Read should have worked");
+        assert_eq!(read.size, 3);
+        assert_eq!(read.payload, value.payload);
+    }
+}
+
+mod endian_from {
+    use super::*;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    #[no_std_io(endian_from = "big_endian")]
+    struct Flagged {
+        length: u16,
+        big_endian: bool,
+        value: u16,
+    }
+
+    #[test]
+    fn should_write_big_endian_after_the_mark() {
+        let value = Flagged {
+            length: 1,
+            big_endian: true,
+            value: 0xaabb,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x01, 0x00, 0x01, 0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn should_write_little_endian_after_the_mark() {
+        let value = Flagged {
+            length: 1,
+            big_endian: false,
+            value: 0xaabb,
+        };
+        let mut bytes = vec![];
+        bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x00, 0x01, 0x00, 0xbb, 0xaa]);
+    }
+}
+
+mod total_size {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct RecordWithRest {
+        #[no_std_io(total_size, computed = "self.get_size() as u16")]
+        size: u16,
+        value: u16,
+        #[no_std_io(rest)]
+        extra: Vec<u8>,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = RecordWithRest {
+            size: 0,
+            value: 0x1122,
+            extra: vec![0xaa, 0xbb],
+        };
+        assert_eq!(value.get_size(), 6);
+    }
+
+    #[test]
+    fn should_write_computed_size_and_rest() {
+        let value = RecordWithRest {
+            size: 0,
+            value: 0x1122,
+            extra: vec![0xaa, 0xbb],
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 6);
+        assert_eq!(bytes, [0x06, 0x00, 0x22, 0x11, 0xaa, 0xbb]);
+    }
+}
+
+mod skip {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct Skipped {
+        first: u8,
+        #[no_std_io(skip)]
+        cache: u32,
+        second: u16,
+    }
+
+    #[test]
+    fn should_not_size_skipped_fields() {
+        let value = Skipped {
+            first: 0xaa,
+            cache: 0xffffffff,
+            second: 0x1122,
+        };
+        assert_eq!(value.get_size(), 3);
+    }
+
+    #[test]
+    fn should_not_write_skipped_fields() {
+        let value = Skipped {
+            first: 0xaa,
+            cache: 0xffffffff,
+            second: 0x1122,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 3);
+        assert_eq!(bytes, [0xaa, 0x22, 0x11]);
+    }
+}
+
+mod magic {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    #[no_std_io(magic = b"NSIO")]
+    struct Header {
+        version: u16,
+    }
+
+    #[test]
+    fn should_get_size() {
+        assert_eq!(Header { version: 1 }.get_size(), 6);
+    }
+
+    #[test]
+    fn should_write_the_magic_first() {
+        let mut bytes = vec![];
+        let result = bytes
+            .write_be(0, &Header { version: 1 })
+            .expect("Write should have worked");
+
+        assert_eq!(result, 6);
+        assert_eq!(bytes, [b'N', b'S', b'I', b'O', 0x00, 0x01]);
+    }
+}
+
+mod size_field {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    #[no_std_io(magic = b"NS")]
+    struct Header {
+        version: u8,
+        #[no_std_io(size_field)]
+        size: u32,
+        #[no_std_io(sized_by = "3")]
+        payload: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct Small {
+        #[no_std_io(size_field)]
+        size: u8,
+        #[no_std_io(sized_by = "300")]
+        payload: Vec<u8>,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = Header {
+            version: 1,
+            size: 0,
+            payload: vec![0xaa, 0xbb, 0xcc],
+        };
+        assert_eq!(value.get_size(), 10);
+    }
+
+    #[test]
+    fn should_patch_the_size_with_the_bytes_written() {
+        let value = Header {
+            version: 1,
+            size: 0xffff,
+            payload: vec![0xaa, 0xbb, 0xcc],
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 10);
+        assert_eq!(
+            bytes,
+            [b'N', b'S', 0x01, 0x00, 0x00, 0x00, 0x0a, 0xaa, 0xbb, 0xcc]
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_the_size_does_not_fit() {
+        let value = Small {
+            size: 0,
+            payload: vec![0; 300],
+        };
+        let mut bytes = vec![];
+        let error = bytes.write_le(0, &value).unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::InvalidWrite {
+                message: "Size does not fit in size field",
+            }
+        );
+    }
+}
+
+mod scale {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct Sensor {
+        #[no_std_io(scale = 100, raw = "u16")]
+        temperature: f32,
+        #[no_std_io(scale = 10, raw = "i8")]
+        offset: f64,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = Sensor {
+            temperature: 1.5,
+            offset: -1.5,
+        };
+        assert_eq!(value.get_size(), 3);
+    }
+
+    #[test]
+    fn should_multiply_by_the_scale() {
+        let value = Sensor {
+            temperature: 1.5,
+            offset: -1.5,
+        };
+        let mut bytes = vec![];
+        bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x00, 0x96, 0xf1]);
+    }
+
+    #[test]
+    fn should_round_halfway_values_away_from_zero() {
+        let value = Sensor {
+            temperature: 0.125,
+            offset: -0.25,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x0d, 0x00, 0xfd]);
+    }
+
+    #[test]
+    fn should_saturate_at_the_bounds_of_the_raw_type() {
+        let value = Sensor {
+            temperature: -1.0,
+            offset: 100.0,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x00, 0x00, 0x7f]);
+    }
+
+    #[derive(Debug, PartialEq, no_std_io::EndianWrite)]
+    struct Unscaled {
+        #[no_std_io(scale = 1, raw = "i8")]
+        first: f64,
+        #[no_std_io(scale = 1, raw = "i8")]
+        second: f64,
+    }
+
+    #[test]
+    fn should_round_negative_values_to_the_nearest_integer() {
+        let value = Unscaled {
+            first: -2.4,
+            second: -2.6,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0xfe, 0xfd]);
+    }
+
+    #[test]
+    fn should_round_values_just_below_halfway_toward_zero() {
+        let value = Unscaled {
+            first: 0.49999999999999994,
+            second: -0.49999999999999994,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x00, 0x00]);
+    }
+}
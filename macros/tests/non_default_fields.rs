@@ -0,0 +1,29 @@
+use no_std_io::{NonDefaultFields, Reader};
+
+#[derive(Debug, Default, PartialEq, no_std_io::EndianRead, no_std_io::NonDefaultFields)]
+struct Header {
+    magic: u32,
+    version: u8,
+    flags: u16,
+}
+
+#[derive(Debug, Default, PartialEq, no_std_io::NonDefaultFields)]
+struct Pair(u8, u8);
+
+#[test]
+fn should_return_no_fields_for_default() {
+    assert!(Header::default().non_default_fields().is_empty());
+}
+
+#[test]
+fn should_return_fields_that_differ_from_default() {
+    let bytes = vec![0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00];
+    let header: Header = bytes.read_le(0).expect("Read should have worked");
+
+    assert_eq!(header.non_default_fields(), ["version", "flags"]);
+}
+
+#[test]
+fn should_use_indexes_for_tuple_structs() {
+    assert_eq!(Pair(0, 1).non_default_fields(), ["1"]);
+}
@@ -0,0 +1,144 @@
+/// Reads a sequence of little endian values starting at an offset and returns them as a tuple.
+///
+/// Each value is read directly after the previous one, so a fixed header can be read like a declaration:
+///
+/// ```
+/// use no_std_io::{destructure_le, ReaderResult};
+///
+/// fn read_header(bytes: &[u8]) -> ReaderResult<([u8; 4], u16, u32)> {
+///     let (magic, version, flags) = destructure_le!(bytes, 0, [u8; 4], u16, u32)?;
+///     Ok((magic, version, flags))
+/// }
+///
+/// let bytes = [0x4d, 0x41, 0x47, 0x43, 0x01, 0x00, 0xaa, 0xbb, 0xcc, 0xdd];
+/// assert_eq!(read_header(&bytes), Ok((*b"MAGC", 1, 0xddccbbaa)));
+/// ```
+#[macro_export]
+macro_rules! destructure_le {
+    ($reader:expr, $offset:expr, $($ty:ty),+ $(,)?) => {
+        (|| -> $crate::ReaderResult<_> {
+            let reader = &$reader;
+            let mut offset: usize = $offset;
+            let offset = &mut offset;
+            Ok(($({
+                let output = $crate::Reader::read_le_with_output::<$ty>(reader, *offset)?;
+                let read_bytes = output.get_read_bytes();
+                *offset = offset.checked_add(read_bytes).ok_or($crate::Error::InvalidSize {
+                    wanted_size: read_bytes,
+                    offset: *offset,
+                    data_len: $crate::Reader::get_slice(reader).len(),
+                })?;
+                output.into_data()
+            },)+))
+        })()
+    };
+}
+
+/// Same as [destructure_le!], but reads big endian values.
+#[macro_export]
+macro_rules! destructure_be {
+    ($reader:expr, $offset:expr, $($ty:ty),+ $(,)?) => {
+        (|| -> $crate::ReaderResult<_> {
+            let reader = &$reader;
+            let mut offset: usize = $offset;
+            let offset = &mut offset;
+            Ok(($({
+                let output = $crate::Reader::read_be_with_output::<$ty>(reader, *offset)?;
+                let read_bytes = output.get_read_bytes();
+                *offset = offset.checked_add(read_bytes).ok_or($crate::Error::InvalidSize {
+                    wanted_size: read_bytes,
+                    offset: *offset,
+                    data_len: $crate::Reader::get_slice(reader).len(),
+                })?;
+                output.into_data()
+            },)+))
+        })()
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+
+    mod destructure_le {
+        use super::*;
+        use crate::{EndianRead, ReadOutput};
+
+        #[test]
+        fn should_read_values_in_sequence() {
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+            let (magic, version, flags) =
+                destructure_le!(bytes, 0, [u8; 4], u16, u32).expect("Read should have worked");
+
+            assert_eq!(magic, [0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(version, 0x2211);
+            assert_eq!(flags, 0x66554433);
+        }
+
+        #[test]
+        fn should_read_from_an_offset() {
+            let bytes = [0x00, 0x00, 0x11, 0x22, 0x33];
+            let (first, second) =
+                destructure_le!(bytes, 2, u8, u16).expect("Read should have worked");
+
+            assert_eq!((first, second), (0x11, 0x3322));
+        }
+
+        #[test]
+        fn should_return_error_if_a_value_is_out_of_bounds() {
+            let bytes = [0x11, 0x22, 0x33, 0x44, 0x55];
+            let error = destructure_le!(bytes, 0, u16, u32).expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 2,
+                    data_len: 5
+                }
+            );
+        }
+
+        struct Oversized;
+
+        impl EndianRead for Oversized {
+            fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                Ok(ReadOutput::new(Oversized, usize::MAX))
+            }
+
+            fn try_read_be(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[test]
+        fn should_return_error_if_the_offset_overflows() {
+            let bytes = [0x11, 0x22, 0x33];
+            let error = destructure_le!(bytes, 1, Oversized, u8)
+                .map(|_| ())
+                .expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: usize::MAX,
+                    offset: 1,
+                    data_len: 3
+                }
+            );
+        }
+    }
+
+    mod destructure_be {
+        #[test]
+        fn should_read_values_in_sequence() {
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+            let (magic, version, flags) =
+                destructure_be!(bytes, 0, [u8; 4], u16, u32).expect("Read should have worked");
+
+            assert_eq!(magic, [0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(version, 0x1122);
+            assert_eq!(flags, 0x33445566);
+        }
+    }
+}
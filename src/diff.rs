@@ -0,0 +1,12 @@
+use alloc::vec::Vec;
+
+/// A list of field names.
+pub type FieldNames = Vec<&'static str>;
+
+/// An interface to report which fields of a value differ from the default value.
+///
+/// This is useful for finding which fields a format actually populates after a read.
+pub trait NonDefaultFields {
+    /// Returns the names of the fields that are not equal to the default value's fields.
+    fn non_default_fields(&self) -> FieldNames;
+}
@@ -0,0 +1,61 @@
+use super::{EndianRead, EndianWrite, ReadOutput};
+use crate::Error;
+
+/// Selects an endianness at compile time so generic code can be
+/// monomorphized to either the little endian or big endian path.
+pub trait Endianness {
+    /// Tries to read the value from its representation in this endianness.
+    fn try_read<T: EndianRead>(bytes: &[u8]) -> Result<ReadOutput<T>, Error>;
+    /// Tries to write the value in its representation in this endianness.
+    /// Returns the number of bytes written.
+    fn try_write<T: EndianWrite>(value: &T, dst: &mut [u8]) -> Result<usize, Error>;
+}
+
+/// A value read from a header that decides the endianness of the data that follows it,
+/// such as a byte order mark.
+///
+/// Used by the derive macros' `endian_from` struct attribute.
+pub trait ByteOrderMark {
+    /// Returns true if the data following the mark is big endian.
+    fn is_big_endian(&self) -> bool;
+}
+
+/// `true` marks big endian data and `false` marks little endian data.
+impl ByteOrderMark for bool {
+    #[inline(always)]
+    fn is_big_endian(&self) -> bool {
+        *self
+    }
+}
+
+/// Marker type for little endian reads and writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LittleEndian;
+
+impl Endianness for LittleEndian {
+    #[inline(always)]
+    fn try_read<T: EndianRead>(bytes: &[u8]) -> Result<ReadOutput<T>, Error> {
+        T::try_read_le(bytes)
+    }
+
+    #[inline(always)]
+    fn try_write<T: EndianWrite>(value: &T, dst: &mut [u8]) -> Result<usize, Error> {
+        value.try_write_le(dst)
+    }
+}
+
+/// Marker type for big endian reads and writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigEndian;
+
+impl Endianness for BigEndian {
+    #[inline(always)]
+    fn try_read<T: EndianRead>(bytes: &[u8]) -> Result<ReadOutput<T>, Error> {
+        T::try_read_be(bytes)
+    }
+
+    #[inline(always)]
+    fn try_write<T: EndianWrite>(value: &T, dst: &mut [u8]) -> Result<usize, Error> {
+        value.try_write_be(dst)
+    }
+}
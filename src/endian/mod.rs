@@ -1,5 +1,11 @@
+mod endianness;
+pub use endianness::*;
+
 mod read;
 pub use read::*;
 
 mod write;
 pub use write::*;
+
+mod norm;
+pub use norm::*;
@@ -0,0 +1,230 @@
+use super::{EndianRead, EndianWrite, ReadOutput};
+use crate::Error;
+
+/// Rounds to the nearest integer, with halfway values rounded away from zero.
+///
+/// The fraction is compared directly instead of adding 0.5,
+/// which would round values just below a half up.
+#[inline(always)]
+fn round_half_away_from_zero(value: f32) -> f32 {
+    let whole = value as i64 as f32;
+    let fraction = value - whole;
+
+    if fraction >= 0.5 {
+        whole + 1.0
+    } else if fraction <= -0.5 {
+        whole - 1.0
+    } else {
+        whole
+    }
+}
+
+macro_rules! impl_unorm {
+    ($($name:ident($i:ident)),*) => {
+        $(
+            /// A normalized value in the range 0.0 to 1.0, stored as an unsigned integer.
+            #[derive(Debug, Default, Clone, Copy, PartialEq)]
+            pub struct $name(pub f32);
+
+            impl $name {
+                /// Converts a stored integer into its normalized value.
+                #[inline(always)]
+                pub fn from_raw(raw: $i) -> Self {
+                    Self(raw as f32 / $i::MAX as f32)
+                }
+
+                /// Converts the normalized value into its stored integer,
+                /// clamping it to the valid range and rounding to the nearest integer.
+                #[inline(always)]
+                pub fn to_raw(self) -> $i {
+                    round_half_away_from_zero(self.0.clamp(0.0, 1.0) * $i::MAX as f32) as $i
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_snorm {
+    ($($name:ident($i:ident)),*) => {
+        $(
+            /// A normalized value in the range -1.0 to 1.0, stored as a signed integer.
+            ///
+            /// Both the minimum and the minimum + 1 integers are read as -1.0.
+            #[derive(Debug, Default, Clone, Copy, PartialEq)]
+            pub struct $name(pub f32);
+
+            impl $name {
+                /// Converts a stored integer into its normalized value.
+                #[inline(always)]
+                pub fn from_raw(raw: $i) -> Self {
+                    Self((raw as f32 / $i::MAX as f32).max(-1.0))
+                }
+
+                /// Converts the normalized value into its stored integer,
+                /// clamping it to the valid range and rounding to the nearest integer.
+                #[inline(always)]
+                pub fn to_raw(self) -> $i {
+                    round_half_away_from_zero(self.0.clamp(-1.0, 1.0) * $i::MAX as f32) as $i
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_norm_endian {
+    ($($name:ident($i:ident)),*) => {
+        $(
+            impl EndianRead for $name {
+                const FIXED_SIZE: Option<usize> = $i::FIXED_SIZE;
+
+                #[inline(always)]
+                fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                    let result = $i::try_read_le(bytes)?;
+                    let read_bytes = result.get_read_bytes();
+                    Ok(ReadOutput::new(Self::from_raw(result.into_data()), read_bytes))
+                }
+
+                #[inline(always)]
+                fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                    let result = $i::try_read_be(bytes)?;
+                    let read_bytes = result.get_read_bytes();
+                    Ok(ReadOutput::new(Self::from_raw(result.into_data()), read_bytes))
+                }
+            }
+
+            impl EndianWrite for $name {
+                #[inline(always)]
+                fn get_size(&self) -> usize {
+                    self.to_raw().get_size()
+                }
+
+                #[inline(always)]
+                fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                    self.to_raw().try_write_le(dst)
+                }
+
+                #[inline(always)]
+                fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                    self.to_raw().try_write_be(dst)
+                }
+            }
+        )*
+    };
+}
+
+impl_unorm!(UNorm8(u8), UNorm16(u16));
+impl_snorm!(SNorm8(i8), SNorm16(i16));
+impl_norm_endian!(UNorm8(u8), UNorm16(u16), SNorm8(i8), SNorm16(i16));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod round_half_away_from_zero {
+        use super::*;
+
+        #[test]
+        fn should_round_halfway_values_away_from_zero() {
+            assert_eq!(round_half_away_from_zero(2.5), 3.0);
+            assert_eq!(round_half_away_from_zero(-2.5), -3.0);
+        }
+
+        #[test]
+        fn should_round_values_just_below_halfway_toward_zero() {
+            assert_eq!(round_half_away_from_zero(0.49999997), 0.0);
+            assert_eq!(round_half_away_from_zero(-0.49999997), 0.0);
+            assert_eq!(round_half_away_from_zero(-2.4), -2.0);
+        }
+    }
+
+    mod unorm {
+        use super::*;
+
+        #[test]
+        fn should_convert_from_raw() {
+            assert_eq!(UNorm8::from_raw(0), UNorm8(0.0));
+            assert_eq!(UNorm8::from_raw(255), UNorm8(1.0));
+            assert_eq!(UNorm16::from_raw(u16::MAX), UNorm16(1.0));
+        }
+
+        #[test]
+        fn should_round_to_nearest() {
+            assert_eq!(UNorm8(0.5).to_raw(), 128);
+            assert_eq!(UNorm8(0.498).to_raw(), 127);
+            assert_eq!(UNorm16(0.5).to_raw(), 32768);
+        }
+
+        #[test]
+        fn should_round_values_just_below_halfway_down() {
+            // Scales to 0.49999997, the largest f32 below 0.5.
+            assert_eq!(UNorm8(0.0019607842).to_raw(), 0);
+        }
+
+        #[test]
+        fn should_clamp_out_of_range_values() {
+            assert_eq!(UNorm8(-0.5).to_raw(), 0);
+            assert_eq!(UNorm8(1.5).to_raw(), 255);
+        }
+
+        #[test]
+        fn should_round_trip_every_value() {
+            for raw in 0..=u8::MAX {
+                assert_eq!(UNorm8::from_raw(raw).to_raw(), raw);
+            }
+        }
+
+        #[test]
+        fn should_read_and_write() {
+            let mut bytes = [0; 2];
+            assert_eq!(UNorm16(1.0).try_write_be(&mut bytes), Ok(2));
+            assert_eq!(bytes, [0xff, 0xff]);
+            assert_eq!(
+                UNorm16::try_read_le(&[0x00, 0x00]),
+                Ok(ReadOutput::new(UNorm16(0.0), 2))
+            );
+        }
+    }
+
+    mod snorm {
+        use super::*;
+
+        #[test]
+        fn should_convert_from_raw() {
+            assert_eq!(SNorm8::from_raw(127), SNorm8(1.0));
+            assert_eq!(SNorm8::from_raw(-127), SNorm8(-1.0));
+            assert_eq!(SNorm8::from_raw(-128), SNorm8(-1.0));
+            assert_eq!(SNorm16::from_raw(0), SNorm16(0.0));
+        }
+
+        #[test]
+        fn should_round_to_nearest() {
+            assert_eq!(SNorm16(0.5).to_raw(), 16384);
+            assert_eq!(SNorm16(-0.5).to_raw(), -16384);
+            assert_eq!(SNorm8(-1.0).to_raw(), -127);
+        }
+
+        #[test]
+        fn should_clamp_out_of_range_values() {
+            assert_eq!(SNorm16(-2.0).to_raw(), -32767);
+            assert_eq!(SNorm16(2.0).to_raw(), 32767);
+        }
+
+        #[test]
+        fn should_round_trip_every_value() {
+            for raw in -127..=i8::MAX {
+                assert_eq!(SNorm8::from_raw(raw).to_raw(), raw);
+            }
+        }
+
+        #[test]
+        fn should_read_and_write() {
+            let mut bytes = [0; 2];
+            assert_eq!(SNorm16(-1.0).try_write_le(&mut bytes), Ok(2));
+            assert_eq!(bytes, [0x01, 0x80]);
+            assert_eq!(
+                SNorm16::try_read_be(&[0x7f, 0xff]),
+                Ok(ReadOutput::new(SNorm16(1.0), 2))
+            );
+        }
+    }
+}
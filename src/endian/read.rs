@@ -1,9 +1,20 @@
-use crate::Error;
-use core::{convert::TryInto, marker::PhantomData, mem};
+use crate::{add_error_context, Error};
+use core::{
+    array,
+    convert::TryInto,
+    marker::PhantomData,
+    mem,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::Range,
+};
 
 /// The result of a read, including the value that was
 /// read and the number of bytes it consumed.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReadOutput<T: Sized> {
     data: T,
     read_bytes: usize,
@@ -35,6 +46,27 @@ impl<T: Sized> ReadOutput<T> {
         let data = self.into_data().into();
         ReadOutput { data, read_bytes }
     }
+
+    /// Converts the data of ReadOutput with a function,
+    /// and retains the read bytes.
+    #[inline(always)]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> ReadOutput<U> {
+        let read_bytes = self.get_read_bytes();
+        let data = f(self.into_data());
+        ReadOutput { data, read_bytes }
+    }
+
+    /// Same as [ReadOutput::map], but the function can fail,
+    /// such as when validating the data.
+    #[inline(always)]
+    pub fn and_then<U, F: FnOnce(T) -> Result<U, Error>>(
+        self,
+        f: F,
+    ) -> Result<ReadOutput<U>, Error> {
+        let read_bytes = self.get_read_bytes();
+        let data = f(self.into_data())?;
+        Ok(ReadOutput { data, read_bytes })
+    }
 }
 
 /// Defines a shared interface to read data from a source that is endian specific.
@@ -42,6 +74,10 @@ impl<T: Sized> ReadOutput<T> {
 /// This should only be used when handling an external data source, such as a remote API or file.
 /// Usually you'll want code to be endian agnostic.
 pub trait EndianRead: Sized {
+    /// The number of bytes read for every value, if it's the same for every value.
+    /// This is only a hint, such as for [Iterator::size_hint], so it's safe to leave as [None].
+    const FIXED_SIZE: Option<usize> = None;
+
     /// Tries to read the value from its little endian representation.
     fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error>;
     /// Tries to read the value from its big endian representation.
@@ -52,6 +88,8 @@ macro_rules! impl_endian_read {
     ($($i:ty),*) => {
         $(
             impl EndianRead for $i {
+                const FIXED_SIZE: Option<usize> = Some(mem::size_of::<$i>());
+
                 #[inline(always)]
                 fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
                     let byte_count = mem::size_of::<$i>();
@@ -92,9 +130,11 @@ macro_rules! impl_endian_read {
     };
 }
 
-impl_endian_read!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64);
+impl_endian_read!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
 
 impl EndianRead for bool {
+    const FIXED_SIZE: Option<usize> = Some(1);
+
     #[inline(always)]
     fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
         let result = u8::try_read_le(bytes)?;
@@ -114,41 +154,137 @@ impl EndianRead for bool {
     }
 }
 
-impl<const SIZE: usize> EndianRead for [u8; SIZE] {
+/// Reads a `u32` code point, which must be a valid Unicode scalar value.
+impl EndianRead for char {
+    const FIXED_SIZE: Option<usize> = Some(4);
+
     #[inline(always)]
     fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
-        if SIZE > bytes.len() {
-            return Err(Error::InvalidSize {
-                wanted_size: SIZE,
-                offset: 0,
-                data_len: bytes.len(),
-            });
-        }
-
-        Ok(ReadOutput {
-            data: bytes[..SIZE].try_into().unwrap(),
-            read_bytes: SIZE,
-        })
+        try_read_char(u32::try_read_le(bytes)?)
     }
 
     #[inline(always)]
     fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
-        if SIZE > bytes.len() {
-            return Err(Error::InvalidSize {
-                wanted_size: SIZE,
-                offset: 0,
-                data_len: bytes.len(),
-            });
-        }
+        try_read_char(u32::try_read_be(bytes)?)
+    }
+}
 
-        Ok(ReadOutput {
-            data: bytes[..SIZE].try_into().unwrap(),
-            read_bytes: SIZE,
+#[inline(always)]
+fn try_read_char(code_point: ReadOutput<u32>) -> Result<ReadOutput<char>, Error> {
+    code_point.and_then(|code_point| {
+        char::from_u32(code_point).ok_or(Error::InvalidRead {
+            message: "Invalid char",
         })
+    })
+}
+
+/// Reads the underlying integer, which must not be zero.
+macro_rules! impl_endian_read_non_zero {
+    ($($non_zero:ty => $i:ty),*) => {
+        $(
+            impl EndianRead for $non_zero {
+                const FIXED_SIZE: Option<usize> = Some(mem::size_of::<$i>());
+
+                #[inline(always)]
+                fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                    <$i>::try_read_le(bytes)?.and_then(|value| {
+                        <$non_zero>::new(value).ok_or(Error::InvalidRead {
+                            message: "Value must not be zero",
+                        })
+                    })
+                }
+
+                #[inline(always)]
+                fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                    <$i>::try_read_be(bytes)?.and_then(|value| {
+                        <$non_zero>::new(value).ok_or(Error::InvalidRead {
+                            message: "Value must not be zero",
+                        })
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_endian_read_non_zero!(
+    NonZeroU8 => u8,
+    NonZeroI8 => i8,
+    NonZeroU16 => u16,
+    NonZeroI16 => i16,
+    NonZeroU32 => u32,
+    NonZeroI32 => i32,
+    NonZeroU64 => u64,
+    NonZeroI64 => i64,
+    NonZeroU128 => u128,
+    NonZeroI128 => i128,
+    NonZeroUsize => usize,
+    NonZeroIsize => isize
+);
+
+/// Reads a value starting at `read_bytes`, then advances `read_bytes` past it.
+#[inline(always)]
+fn try_read_next<T: EndianRead>(
+    bytes: &[u8],
+    read_bytes: &mut usize,
+    try_read: fn(&[u8]) -> Result<ReadOutput<T>, Error>,
+) -> Result<T, Error> {
+    let value_bytes = bytes.get(*read_bytes..).unwrap_or_default();
+    let result = add_error_context(try_read(value_bytes), *read_bytes, bytes.len())?;
+    *read_bytes += result.get_read_bytes();
+    Ok(result.into_data())
+}
+
+/// Reads each element of an array in order, starting where the previous element ended.
+#[inline(always)]
+fn try_read_array<T: EndianRead, const SIZE: usize>(
+    bytes: &[u8],
+    try_read: fn(&[u8]) -> Result<ReadOutput<T>, Error>,
+) -> Result<ReadOutput<[T; SIZE]>, Error> {
+    let mut read_bytes = 0;
+    let mut error = None;
+    let values: [Option<T>; SIZE] = array::from_fn(|_| {
+        if error.is_some() {
+            return None;
+        }
+
+        match try_read_next(bytes, &mut read_bytes, try_read) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(ReadOutput::new(values.map(Option::unwrap), read_bytes))
+}
+
+/// Reads each element in order, so the array's size is the sum of its elements' sizes.
+impl<T: EndianRead, const SIZE: usize> EndianRead for [T; SIZE] {
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => size.checked_mul(SIZE),
+        None => None,
+    };
+
+    #[inline(always)]
+    fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+        try_read_array(bytes, T::try_read_le)
+    }
+
+    #[inline(always)]
+    fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+        try_read_array(bytes, T::try_read_be)
     }
 }
 
 impl EndianRead for () {
+    const FIXED_SIZE: Option<usize> = Some(0);
+
     #[inline(always)]
     fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
         Ok(ReadOutput::new((), 0))
@@ -161,6 +297,8 @@ impl EndianRead for () {
 }
 
 impl<T: EndianRead> EndianRead for PhantomData<T> {
+    const FIXED_SIZE: Option<usize> = Some(0);
+
     #[inline(always)]
     fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
         Ok(ReadOutput::new(PhantomData, 0))
@@ -171,3 +309,665 @@ impl<T: EndianRead> EndianRead for PhantomData<T> {
         Ok(ReadOutput::new(PhantomData, 0))
     }
 }
+
+// Implements tuples from the full list of types down to a single type.
+macro_rules! impl_endian_read_tuple {
+    () => {};
+    ($head:ident $(, $tail:ident)*) => {
+        impl<$head: EndianRead, $($tail: EndianRead),*> EndianRead for ($head, $($tail,)*) {
+            #[inline(always)]
+            fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                let mut read_bytes = 0;
+                let data = (
+                    try_read_next(bytes, &mut read_bytes, $head::try_read_le)?,
+                    $(try_read_next(bytes, &mut read_bytes, $tail::try_read_le)?,)*
+                );
+                Ok(ReadOutput::new(data, read_bytes))
+            }
+
+            #[inline(always)]
+            fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                let mut read_bytes = 0;
+                let data = (
+                    try_read_next(bytes, &mut read_bytes, $head::try_read_be)?,
+                    $(try_read_next(bytes, &mut read_bytes, $tail::try_read_be)?,)*
+                );
+                Ok(ReadOutput::new(data, read_bytes))
+            }
+        }
+
+        impl_endian_read_tuple!($($tail),*);
+    };
+}
+
+impl_endian_read_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Reads the start followed by the end, which must not be before the start.
+impl<T: EndianRead + PartialOrd> EndianRead for Range<T> {
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => Some(size * 2),
+        None => None,
+    };
+
+    #[inline(always)]
+    fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+        <(T, T)>::try_read_le(bytes)?.and_then(try_read_range)
+    }
+
+    #[inline(always)]
+    fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+        <(T, T)>::try_read_be(bytes)?.and_then(try_read_range)
+    }
+}
+
+#[inline(always)]
+fn try_read_range<T: PartialOrd>((start, end): (T, T)) -> Result<Range<T>, Error> {
+    match start <= end {
+        true => Ok(start..end),
+        false => Err(Error::InvalidRead {
+            message: "Range start is after its end",
+        }),
+    }
+}
+
+/// Reads a presence byte, followed by the value if the byte is `0x01`.
+impl<T: EndianRead> EndianRead for Option<T> {
+    #[inline(always)]
+    fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+        try_read_option(bytes, T::try_read_le)
+    }
+
+    #[inline(always)]
+    fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+        try_read_option(bytes, T::try_read_be)
+    }
+}
+
+#[inline(always)]
+fn try_read_option<T: EndianRead>(
+    bytes: &[u8],
+    try_read: fn(&[u8]) -> Result<ReadOutput<T>, Error>,
+) -> Result<ReadOutput<Option<T>>, Error> {
+    match u8::try_read_le(bytes)?.into_data() {
+        0 => Ok(ReadOutput::new(None, 1)),
+        1 => {
+            let result = add_error_context(try_read(&bytes[1..]), 1, bytes.len())?;
+            let read_bytes = result.get_read_bytes() + 1;
+            Ok(ReadOutput::new(Some(result.into_data()), read_bytes))
+        }
+        _ => Err(Error::InvalidRead {
+            message: "Invalid presence flag",
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Cursor, EndianWrite, Reader};
+
+    mod read_output {
+        use super::*;
+
+        #[test]
+        fn should_keep_the_read_bytes_when_mapping() {
+            let output = ReadOutput::new(0x1122u16, 2).map(|value| value as u32 * 2);
+            assert_eq!(output, ReadOutput::new(0x2244u32, 2));
+        }
+
+        #[test]
+        fn should_keep_the_read_bytes_when_chaining() {
+            let output = ReadOutput::new(4u8, 1).and_then(|value| Ok(value == 4));
+            assert_eq!(output, Ok(ReadOutput::new(true, 1)));
+        }
+
+        #[test]
+        fn should_return_the_error_when_chaining() {
+            let output = ReadOutput::new(4u8, 1).and_then(|_| -> Result<bool, Error> {
+                Err(Error::InvalidRead {
+                    message: "Invalid value",
+                })
+            });
+            assert_eq!(
+                output,
+                Err(Error::InvalidRead {
+                    message: "Invalid value",
+                })
+            );
+        }
+    }
+
+    mod floats {
+        use super::*;
+
+        fn round_trip_le<T: EndianRead + EndianWrite>(value: T) -> T {
+            let mut bytes = [0; 8];
+            let written = value.try_write_le(&mut bytes).unwrap();
+            let result = T::try_read_le(&bytes).unwrap();
+            assert_eq!(result.get_read_bytes(), written);
+            result.into_data()
+        }
+
+        fn round_trip_be<T: EndianRead + EndianWrite>(value: T) -> T {
+            let mut bytes = [0; 8];
+            let written = value.try_write_be(&mut bytes).unwrap();
+            let result = T::try_read_be(&bytes).unwrap();
+            assert_eq!(result.get_read_bytes(), written);
+            result.into_data()
+        }
+
+        #[test]
+        fn should_read_f32() {
+            let bytes = [0x00, 0x00, 0x80, 0x3f];
+            let result = f32::try_read_le(&bytes).unwrap();
+            assert_eq!(result, ReadOutput::new(1.0, 4));
+
+            let result = f32::try_read_be(&[0x3f, 0x80, 0x00, 0x00]).unwrap();
+            assert_eq!(result, ReadOutput::new(1.0, 4));
+        }
+
+        #[test]
+        fn should_read_f64() {
+            let bytes = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f];
+            let result = f64::try_read_le(&bytes).unwrap();
+            assert_eq!(result, ReadOutput::new(1.0, 8));
+        }
+
+        #[test]
+        fn should_preserve_f32_bit_patterns() {
+            for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0f32] {
+                assert_eq!(round_trip_le(value).to_bits(), value.to_bits());
+                assert_eq!(round_trip_be(value).to_bits(), value.to_bits());
+            }
+        }
+
+        #[test]
+        fn should_preserve_f64_bit_patterns() {
+            for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0f64] {
+                assert_eq!(round_trip_le(value).to_bits(), value.to_bits());
+                assert_eq!(round_trip_be(value).to_bits(), value.to_bits());
+            }
+        }
+
+        #[test]
+        fn should_return_error_if_there_are_not_enough_bytes() {
+            let error = f32::try_read_le(&[0x00, 0x00, 0x80]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 0,
+                    data_len: 3,
+                }
+            );
+
+            let error = f64::try_read_be(&[0x00, 0x00, 0x80, 0x3f]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 0,
+                    data_len: 4,
+                }
+            );
+        }
+    }
+
+    mod bool {
+        use super::*;
+        use crate::{StreamContainer, StreamReader, StreamWriter};
+
+        #[test]
+        fn should_read_zero_as_false() {
+            let result = bool::try_read_le(&[0x00]).unwrap();
+            assert_eq!(result, ReadOutput::new(false, 1));
+        }
+
+        #[test]
+        fn should_read_one_as_true() {
+            let result = bool::try_read_le(&[0x01]).unwrap();
+            assert_eq!(result, ReadOutput::new(true, 1));
+        }
+
+        #[test]
+        fn should_read_any_nonzero_value_as_true() {
+            let result = bool::try_read_be(&[0xff]).unwrap();
+            assert_eq!(result, ReadOutput::new(true, 1));
+        }
+
+        #[test]
+        fn should_return_error_if_there_are_not_enough_bytes() {
+            let error = bool::try_read_le(&[]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 1,
+                    offset: 0,
+                    data_len: 0,
+                }
+            );
+        }
+
+        #[test]
+        fn should_round_trip_through_a_stream() {
+            let mut stream = StreamContainer::new([0xaa; 2]);
+            stream.write_stream_le(&true).unwrap();
+            stream.write_stream_be(&false).unwrap();
+            assert_eq!(stream.get_slice(), [0x01, 0x00]);
+
+            stream.set_index(0);
+            assert_eq!(stream.read_stream_le::<bool>(), Ok(true));
+            assert_eq!(stream.read_stream_be::<bool>(), Ok(false));
+        }
+    }
+
+    mod char {
+        use super::*;
+
+        #[test]
+        fn should_read_a_valid_code_point() {
+            let result = char::try_read_le(&[0x00, 0xf6, 0x01, 0x00]).unwrap();
+            assert_eq!(result, ReadOutput::new('😀', 4));
+
+            let result = char::try_read_be(&[0x00, 0x00, 0x00, 0x41]).unwrap();
+            assert_eq!(result, ReadOutput::new('A', 4));
+        }
+
+        #[test]
+        fn should_return_error_for_a_surrogate() {
+            let error = char::try_read_le(&[0x00, 0xd8, 0x00, 0x00]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid char",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_for_an_out_of_range_code_point() {
+            let error = char::try_read_be(&[0x00, 0x11, 0x00, 0x00]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid char",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_there_are_not_enough_bytes() {
+            let error = char::try_read_le(&[0x41, 0x00]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 0,
+                    data_len: 2,
+                }
+            );
+        }
+    }
+
+    mod non_zero {
+        use super::*;
+
+        #[test]
+        fn should_read_a_nonzero_value() {
+            let result = NonZeroU8::try_read_le(&[0x12]).unwrap();
+            assert_eq!(result, ReadOutput::new(NonZeroU8::new(0x12).unwrap(), 1));
+
+            let result = NonZeroU32::try_read_be(&[0x11, 0x22, 0x33, 0x44]).unwrap();
+            assert_eq!(
+                result,
+                ReadOutput::new(NonZeroU32::new(0x11223344).unwrap(), 4)
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_the_value_is_zero() {
+            let error = NonZeroU8::try_read_le(&[0x00]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Value must not be zero",
+                }
+            );
+
+            let error = NonZeroU32::try_read_be(&[0x00, 0x00, 0x00, 0x00]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Value must not be zero",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_there_are_not_enough_bytes() {
+            let error = NonZeroU32::try_read_le(&[0x01, 0x00]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 0,
+                    data_len: 2,
+                }
+            );
+        }
+    }
+
+    mod u128 {
+        use super::*;
+        use crate::{Reader, Writer};
+
+        const BYTES: [u8; 16] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+
+        #[test]
+        fn should_read_le() {
+            let result = u128::try_read_le(&BYTES).unwrap();
+            assert_eq!(
+                result,
+                ReadOutput::new(0xffeeddccbbaa99887766554433221100, 16)
+            );
+        }
+
+        #[test]
+        fn should_read_be() {
+            let result = u128::try_read_be(&BYTES).unwrap();
+            assert_eq!(
+                result,
+                ReadOutput::new(0x00112233445566778899aabbccddeeff, 16)
+            );
+        }
+
+        #[test]
+        fn should_read_signed_values() {
+            let result = i128::try_read_be(&[0xff; 16]).unwrap();
+            assert_eq!(result, ReadOutput::new(-1, 16));
+        }
+
+        #[test]
+        fn should_round_trip_le() {
+            let mut bytes = [0; 16];
+            bytes
+                .write_le(0, &0x00112233445566778899aabbccddeeffu128)
+                .unwrap();
+            assert_eq!(
+                bytes.read_le::<u128>(0),
+                Ok(0x00112233445566778899aabbccddeeff)
+            );
+        }
+
+        #[test]
+        fn should_round_trip_be() {
+            let mut bytes = [0; 16];
+            bytes
+                .write_be(0, &-0x00112233445566778899aabbccddeeffi128)
+                .unwrap();
+            assert_eq!(
+                bytes.read_be::<i128>(0),
+                Ok(-0x00112233445566778899aabbccddeeff)
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_there_are_not_enough_bytes() {
+            let error = u128::try_read_le(&BYTES[..15]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 16,
+                    offset: 0,
+                    data_len: 15,
+                }
+            );
+        }
+    }
+
+    mod arrays {
+        use super::*;
+
+        #[test]
+        fn should_read_le_elements_in_order() {
+            let bytes = [0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0xff];
+            let result = <[u16; 4]>::try_read_le(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new([1, 2, 3, 4], 8));
+        }
+
+        #[test]
+        fn should_read_be_elements_in_order() {
+            let bytes = [0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff];
+            let result = <[i32; 2]>::try_read_be(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new([1, -1], 8));
+        }
+
+        #[test]
+        fn should_read_zero_bytes_for_an_empty_array() {
+            let result = <[u32; 0]>::try_read_le(&[]).unwrap();
+            assert_eq!(result, ReadOutput::new([], 0));
+        }
+
+        #[test]
+        fn should_return_error_with_the_failed_element_offset() {
+            let bytes = [0x01, 0x00, 0x02, 0x00, 0x03];
+            let error = <[u16; 4]>::try_read_le(&bytes).unwrap_err();
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 4,
+                    data_len: 5,
+                }
+            );
+        }
+
+        #[test]
+        fn should_read_arrays_with_a_reader() {
+            let bytes = [0xaa, 0x00, 0x01, 0x00, 0x02];
+            let result: [u16; 2] = bytes.read_be(1).unwrap();
+            assert_eq!(result, [1, 2]);
+        }
+
+        #[test]
+        fn should_round_trip() {
+            let value: [u16; 4] = [0x1122, 0x3344, 0x5566, 0x7788];
+            let mut bytes = [0; 8];
+            value.try_write_le(&mut bytes).unwrap();
+            let result = <[u16; 4]>::try_read_le(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new(value, 8));
+        }
+
+        #[test]
+        fn should_round_trip_non_primitive_elements() {
+            let value = [(0x11u8, Some(0x2233u16)), (0x44, None)];
+            let mut bytes = [0; 6];
+            assert_eq!(value.try_write_be(&mut bytes), Ok(6));
+            assert_eq!(bytes, [0x11, 0x01, 0x22, 0x33, 0x44, 0x00]);
+
+            let result = <[(u8, Option<u16>); 2]>::try_read_be(&bytes).unwrap();
+            assert_eq!(result, ReadOutput::new(value, 6));
+        }
+
+        #[test]
+        fn should_get_fixed_size_from_the_elements() {
+            assert_eq!(<[u16; 4]>::FIXED_SIZE, Some(8));
+            assert_eq!(<[NonZeroU16; 0]>::FIXED_SIZE, Some(0));
+            assert_eq!(<[Option<u16>; 2]>::FIXED_SIZE, None);
+        }
+    }
+
+    mod range {
+        use super::*;
+
+        #[test]
+        fn should_read_the_start_then_the_end() {
+            let bytes = [0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00];
+            let result = Range::<u32>::try_read_le(&bytes).unwrap();
+            assert_eq!(result, ReadOutput::new(1..16, 8));
+
+            let result = Range::<u16>::try_read_be(&[0x00, 0x02, 0x00, 0x02]).unwrap();
+            assert_eq!(result, ReadOutput::new(2..2, 4));
+        }
+
+        #[test]
+        fn should_return_error_if_the_range_is_inverted() {
+            let bytes = [0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+            let error = Range::<u32>::try_read_le(&bytes).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Range start is after its end",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_the_end_is_missing() {
+            let bytes = [0x01, 0x00, 0x00, 0x00, 0x10, 0x00];
+            let error = Range::<u32>::try_read_le(&bytes).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 4,
+                    data_len: 6,
+                }
+            );
+        }
+    }
+
+    mod option {
+        use super::*;
+
+        #[test]
+        fn should_read_none() {
+            let result = <Option<u32>>::try_read_le(&[0x00, 0xaa]).unwrap();
+            assert_eq!(result, ReadOutput::new(None, 1));
+        }
+
+        #[test]
+        fn should_read_some() {
+            let result = <Option<u16>>::try_read_be(&[0x01, 0xaa, 0xbb]).unwrap();
+            assert_eq!(result, ReadOutput::new(Some(0xaabb), 3));
+        }
+
+        #[test]
+        fn should_return_error_for_an_invalid_flag() {
+            let error = <Option<u16>>::try_read_le(&[0x02, 0xaa, 0xbb]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid presence flag",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_with_the_value_offset() {
+            let error = <Option<u32>>::try_read_le(&[0x01, 0xaa, 0xbb]).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 1,
+                    data_len: 3,
+                }
+            );
+        }
+
+        #[test]
+        fn should_round_trip() {
+            let values: [Option<u16>; 2] = [None, Some(0x1122)];
+
+            for value in values {
+                let mut bytes = [0; 3];
+                let written = value.try_write_le(&mut bytes).unwrap();
+                let result = <Option<u16>>::try_read_le(&bytes).unwrap();
+
+                assert_eq!(result, ReadOutput::new(value, written));
+            }
+        }
+    }
+
+    mod tuples {
+        use super::*;
+
+        #[test]
+        fn should_read_le_elements_in_order() {
+            let bytes = [0x01, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0xff];
+            let result = <(u8, u16, u32)>::try_read_le(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new((1, 2, 3), 7));
+        }
+
+        #[test]
+        fn should_read_be_elements_in_order() {
+            let bytes = [0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03];
+            let result = <(u8, u16, u32)>::try_read_be(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new((1, 2, 3), 7));
+        }
+
+        #[test]
+        fn should_read_tuples_with_a_reader() {
+            let bytes = [0xaa, 0x01, 0x02, 0x00, 0x00, 0x00];
+            let (first, second): (u8, u32) = bytes.read_le(1).unwrap();
+
+            assert_eq!(first, 1);
+            assert_eq!(second, 2);
+        }
+
+        #[test]
+        fn should_read_twelve_elements() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+            let result = <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>::try_read_le(&bytes)
+                .unwrap()
+                .into_data();
+
+            assert_eq!(result, (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+        }
+
+        #[test]
+        fn should_return_error_with_the_failed_element_offset() {
+            let bytes = [0x01, 0x02, 0x00, 0x03];
+            let error = <(u8, u16, u32)>::try_read_le(&bytes).unwrap_err();
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 3,
+                    data_len: 4,
+                }
+            );
+        }
+
+        #[test]
+        fn should_round_trip_le() {
+            let value: (u8, u16, u32) = (0x11, 0x2233, 0x44556677);
+            let mut bytes = [0; 7];
+            value.try_write_le(&mut bytes).unwrap();
+            let result = <(u8, u16, u32)>::try_read_le(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new(value, 7));
+        }
+
+        #[test]
+        fn should_round_trip_be() {
+            let value: (u8, u16, u32) = (0x11, 0x2233, 0x44556677);
+            let mut bytes = [0; 7];
+            value.try_write_be(&mut bytes).unwrap();
+            let result = <(u8, u16, u32)>::try_read_be(&bytes).unwrap();
+
+            assert_eq!(result, ReadOutput::new(value, 7));
+        }
+    }
+}
@@ -1,5 +1,13 @@
-use crate::Error;
-use core::{marker::PhantomData, mem};
+use crate::{add_error_context, Error};
+use core::{
+    marker::PhantomData,
+    mem,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::Range,
+};
 
 /// Defines a shared interface to write data to a source that is endian specific.
 ///
@@ -63,7 +71,7 @@ macro_rules! impl_endian_write {
     };
 }
 
-impl_endian_write!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64);
+impl_endian_write!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
 
 impl EndianWrite for bool {
     #[inline(always)]
@@ -106,38 +114,108 @@ impl EndianWrite for bool {
     }
 }
 
-impl<const SIZE: usize> EndianWrite for [u8; SIZE] {
+/// Writes the code point as a `u32`.
+impl EndianWrite for char {
     #[inline(always)]
     fn get_size(&self) -> usize {
-        SIZE
+        mem::size_of::<u32>()
     }
 
     #[inline(always)]
     fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
-        if SIZE > dst.len() {
-            return Err(Error::InvalidSize {
-                wanted_size: SIZE,
-                offset: 0,
-                data_len: dst.len(),
-            });
-        }
-
-        dst[..SIZE].copy_from_slice(self);
-        Ok(SIZE)
+        u32::from(*self).try_write_le(dst)
     }
 
     #[inline(always)]
     fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
-        if SIZE > dst.len() {
-            return Err(Error::InvalidSize {
-                wanted_size: SIZE,
-                offset: 0,
-                data_len: dst.len(),
-            });
-        }
+        u32::from(*self).try_write_be(dst)
+    }
+}
 
-        dst[..SIZE].copy_from_slice(self);
-        Ok(SIZE)
+/// Writes the underlying integer.
+macro_rules! impl_endian_write_non_zero {
+    ($($non_zero:ty),*) => {
+        $(
+            impl EndianWrite for $non_zero {
+                #[inline(always)]
+                fn get_size(&self) -> usize {
+                    mem::size_of::<$non_zero>()
+                }
+
+                #[inline(always)]
+                fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                    self.get().try_write_le(dst)
+                }
+
+                #[inline(always)]
+                fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                    self.get().try_write_be(dst)
+                }
+            }
+        )*
+    };
+}
+
+impl_endian_write_non_zero!(
+    NonZeroU8,
+    NonZeroI8,
+    NonZeroU16,
+    NonZeroI16,
+    NonZeroU32,
+    NonZeroI32,
+    NonZeroU64,
+    NonZeroI64,
+    NonZeroU128,
+    NonZeroI128,
+    NonZeroUsize,
+    NonZeroIsize
+);
+
+/// Writes a value starting at `bytes_written`, then advances `bytes_written` past it.
+#[inline(always)]
+fn try_write_next<T: EndianWrite>(
+    value: &T,
+    dst: &mut [u8],
+    bytes_written: &mut usize,
+    try_write: fn(&T, &mut [u8]) -> Result<usize, Error>,
+) -> Result<(), Error> {
+    let dst_len = dst.len();
+    let value_dst = dst.get_mut(*bytes_written..).unwrap_or_default();
+    *bytes_written += add_error_context(try_write(value, value_dst), *bytes_written, dst_len)?;
+    Ok(())
+}
+
+/// Writes each element of an array in order, starting where the previous element ended.
+#[inline(always)]
+fn try_write_array<T: EndianWrite>(
+    values: &[T],
+    dst: &mut [u8],
+    try_write: fn(&T, &mut [u8]) -> Result<usize, Error>,
+) -> Result<usize, Error> {
+    let mut bytes_written = 0;
+
+    for value in values {
+        try_write_next(value, dst, &mut bytes_written, try_write)?;
+    }
+
+    Ok(bytes_written)
+}
+
+/// Writes each element in order, so the array's size is the sum of its elements' sizes.
+impl<T: EndianWrite, const SIZE: usize> EndianWrite for [T; SIZE] {
+    #[inline(always)]
+    fn get_size(&self) -> usize {
+        self.iter().map(EndianWrite::get_size).sum()
+    }
+
+    #[inline(always)]
+    fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        try_write_array(self, dst, T::try_write_le)
+    }
+
+    #[inline(always)]
+    fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        try_write_array(self, dst, T::try_write_be)
     }
 }
 
@@ -174,3 +252,439 @@ impl<T: EndianWrite> EndianWrite for PhantomData<T> {
         Ok(0)
     }
 }
+
+// Implements tuples from the full list of types down to a single type.
+macro_rules! impl_endian_write_tuple {
+    () => {};
+    ($head:ident $(, $tail:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$head: EndianWrite, $($tail: EndianWrite),*> EndianWrite for ($head, $($tail,)*) {
+            #[inline(always)]
+            fn get_size(&self) -> usize {
+                let ($head, $($tail,)*) = self;
+                $head.get_size() $(+ $tail.get_size())*
+            }
+
+            #[inline(always)]
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                let ($head, $($tail,)*) = self;
+                let mut bytes_written = 0;
+                try_write_next($head, dst, &mut bytes_written, $head::try_write_le)?;
+                $(try_write_next($tail, dst, &mut bytes_written, $tail::try_write_le)?;)*
+                Ok(bytes_written)
+            }
+
+            #[inline(always)]
+            fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                let ($head, $($tail,)*) = self;
+                let mut bytes_written = 0;
+                try_write_next($head, dst, &mut bytes_written, $head::try_write_be)?;
+                $(try_write_next($tail, dst, &mut bytes_written, $tail::try_write_be)?;)*
+                Ok(bytes_written)
+            }
+        }
+
+        impl_endian_write_tuple!($($tail),*);
+    };
+}
+
+impl_endian_write_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Writes the start followed by the end.
+impl<T: EndianWrite> EndianWrite for Range<T> {
+    #[inline(always)]
+    fn get_size(&self) -> usize {
+        self.start.get_size() + self.end.get_size()
+    }
+
+    #[inline(always)]
+    fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        let mut bytes_written = 0;
+        try_write_next(&self.start, dst, &mut bytes_written, T::try_write_le)?;
+        try_write_next(&self.end, dst, &mut bytes_written, T::try_write_le)?;
+        Ok(bytes_written)
+    }
+
+    #[inline(always)]
+    fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        let mut bytes_written = 0;
+        try_write_next(&self.start, dst, &mut bytes_written, T::try_write_be)?;
+        try_write_next(&self.end, dst, &mut bytes_written, T::try_write_be)?;
+        Ok(bytes_written)
+    }
+}
+
+/// Writes the referenced value.
+impl<T: EndianWrite + ?Sized> EndianWrite for &T {
+    #[inline(always)]
+    fn get_size(&self) -> usize {
+        T::get_size(self)
+    }
+
+    #[inline(always)]
+    fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        T::try_write_le(self, dst)
+    }
+
+    #[inline(always)]
+    fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        T::try_write_be(self, dst)
+    }
+}
+
+/// Writes `0x00` for [None], or `0x01` followed by the value for [Some].
+impl<T: EndianWrite> EndianWrite for Option<T> {
+    #[inline(always)]
+    fn get_size(&self) -> usize {
+        match self {
+            Some(value) => 1 + value.get_size(),
+            None => 1,
+        }
+    }
+
+    #[inline(always)]
+    fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        try_write_option(self, dst, T::try_write_le)
+    }
+
+    #[inline(always)]
+    fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        try_write_option(self, dst, T::try_write_be)
+    }
+}
+
+#[inline(always)]
+fn try_write_option<T: EndianWrite>(
+    option: &Option<T>,
+    dst: &mut [u8],
+    try_write: fn(&T, &mut [u8]) -> Result<usize, Error>,
+) -> Result<usize, Error> {
+    match option {
+        Some(value) => {
+            1u8.try_write_le(dst)?;
+            let dst_len = dst.len();
+            let bytes_written = add_error_context(try_write(value, &mut dst[1..]), 1, dst_len)?;
+            Ok(bytes_written + 1)
+        }
+        None => 0u8.try_write_le(dst),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod floats {
+        use super::*;
+
+        #[test]
+        fn should_get_size() {
+            assert_eq!(1.0f32.get_size(), 4);
+            assert_eq!(1.0f64.get_size(), 8);
+        }
+
+        #[test]
+        fn should_write_f32() {
+            let mut bytes = [0; 4];
+            assert_eq!(1.0f32.try_write_le(&mut bytes), Ok(4));
+            assert_eq!(bytes, [0x00, 0x00, 0x80, 0x3f]);
+
+            assert_eq!(1.0f32.try_write_be(&mut bytes), Ok(4));
+            assert_eq!(bytes, [0x3f, 0x80, 0x00, 0x00]);
+        }
+
+        #[test]
+        fn should_return_error_if_there_is_not_enough_space() {
+            let mut bytes = [0; 4];
+            let error = 1.0f64.try_write_le(&mut bytes).unwrap_err();
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 0,
+                    data_len: 4,
+                }
+            );
+        }
+    }
+
+    mod bool {
+        use super::*;
+
+        #[test]
+        fn should_get_size() {
+            assert_eq!(true.get_size(), 1);
+        }
+
+        #[test]
+        fn should_write_false_as_zero_and_true_as_one() {
+            let mut bytes = [0xaa; 2];
+            assert_eq!(false.try_write_le(&mut bytes[..1]), Ok(1));
+            assert_eq!(true.try_write_be(&mut bytes[1..]), Ok(1));
+            assert_eq!(bytes, [0x00, 0x01]);
+        }
+    }
+
+    mod char {
+        use super::*;
+        use crate::EndianRead;
+
+        #[test]
+        fn should_get_size() {
+            assert_eq!('A'.get_size(), 4);
+        }
+
+        #[test]
+        fn should_write_the_code_point() {
+            let mut bytes = [0; 8];
+            assert_eq!('😀'.try_write_le(&mut bytes[..4]), Ok(4));
+            assert_eq!('A'.try_write_be(&mut bytes[4..]), Ok(4));
+            assert_eq!(bytes, [0x00, 0xf6, 0x01, 0x00, 0x00, 0x00, 0x00, 0x41]);
+        }
+
+        #[test]
+        fn should_round_trip() {
+            let mut bytes = [0; 4];
+            '\u{10ffff}'.try_write_be(&mut bytes).unwrap();
+            assert_eq!(char::try_read_be(&bytes).unwrap().into_data(), '\u{10ffff}');
+        }
+    }
+
+    mod non_zero {
+        use super::*;
+        use crate::EndianRead;
+
+        #[test]
+        fn should_get_size() {
+            assert_eq!(NonZeroU8::new(1).unwrap().get_size(), 1);
+            assert_eq!(NonZeroU32::new(1).unwrap().get_size(), 4);
+        }
+
+        #[test]
+        fn should_round_trip_non_zero_u8() {
+            let value = NonZeroU8::new(0x12).unwrap();
+            let mut bytes = [0; 1];
+            assert_eq!(value.try_write_le(&mut bytes), Ok(1));
+            assert_eq!(bytes, [0x12]);
+            assert_eq!(NonZeroU8::try_read_le(&bytes).unwrap().into_data(), value);
+        }
+
+        #[test]
+        fn should_round_trip_non_zero_u32() {
+            let value = NonZeroU32::new(0x11223344).unwrap();
+            let mut bytes = [0; 4];
+            assert_eq!(value.try_write_be(&mut bytes), Ok(4));
+            assert_eq!(bytes, [0x11, 0x22, 0x33, 0x44]);
+            assert_eq!(NonZeroU32::try_read_be(&bytes).unwrap().into_data(), value);
+        }
+    }
+
+    mod u128 {
+        use super::*;
+
+        #[test]
+        fn should_get_size() {
+            assert_eq!(0u128.get_size(), 16);
+            assert_eq!(0i128.get_size(), 16);
+        }
+
+        #[test]
+        fn should_write_le() {
+            let mut bytes = [0; 16];
+            let written = 0x00112233445566778899aabbccddeeffu128.try_write_le(&mut bytes);
+            assert_eq!(written, Ok(16));
+            assert_eq!(
+                bytes,
+                [
+                    0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33,
+                    0x22, 0x11, 0x00
+                ]
+            );
+        }
+
+        #[test]
+        fn should_write_be() {
+            let mut bytes = [0; 16];
+            let written = 0x00112233445566778899aabbccddeeffu128.try_write_be(&mut bytes);
+            assert_eq!(written, Ok(16));
+            assert_eq!(
+                bytes,
+                [
+                    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc,
+                    0xdd, 0xee, 0xff
+                ]
+            );
+        }
+    }
+
+    mod arrays {
+        use super::*;
+
+        #[test]
+        fn should_write_le_elements_in_order() {
+            let mut bytes = [0xff; 9];
+            let written = [1u16, 2, 3, 4].try_write_le(&mut bytes).unwrap();
+
+            assert_eq!(written, 8);
+            assert_eq!(
+                bytes,
+                [0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0xff]
+            );
+        }
+
+        #[test]
+        fn should_write_be_elements_in_order() {
+            let mut bytes = [0; 8];
+            let written = [1i32, -1].try_write_be(&mut bytes).unwrap();
+
+            assert_eq!(written, 8);
+            assert_eq!(bytes, [0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff]);
+        }
+
+        #[test]
+        fn should_write_zero_bytes_for_an_empty_array() {
+            let value: [u32; 0] = [];
+            assert_eq!(value.get_size(), 0);
+            assert_eq!(value.try_write_le(&mut []), Ok(0));
+        }
+
+        #[test]
+        fn should_return_error_with_the_failed_element_offset() {
+            let mut bytes = [0; 5];
+            let error = [1u16, 2, 3, 4].try_write_le(&mut bytes).unwrap_err();
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 4,
+                    data_len: 5,
+                }
+            );
+        }
+
+        #[test]
+        fn should_write_non_primitive_elements() {
+            let value = ['a', 'é'];
+            let mut bytes = [0; 8];
+
+            assert_eq!(value.get_size(), 8);
+            assert_eq!(value.try_write_le(&mut bytes), Ok(8));
+            assert_eq!(bytes, [0x61, 0x00, 0x00, 0x00, 0xe9, 0x00, 0x00, 0x00]);
+        }
+    }
+
+    mod range {
+        use super::*;
+        use crate::EndianRead;
+
+        #[test]
+        fn should_get_size() {
+            assert_eq!((1u32..16).get_size(), 8);
+        }
+
+        #[test]
+        fn should_write_the_start_then_the_end() {
+            let mut bytes = [0; 8];
+            assert_eq!((1u32..16).try_write_be(&mut bytes), Ok(8));
+            assert_eq!(bytes, [0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10]);
+            assert_eq!(
+                Range::<u32>::try_read_be(&bytes).unwrap().into_data(),
+                1..16
+            );
+        }
+    }
+
+    mod references {
+        use super::*;
+
+        #[test]
+        fn should_write_the_referenced_value() {
+            let value = &0xaabbu16;
+            let mut bytes = [0; 2];
+
+            assert_eq!(<&u16>::get_size(&value), 2);
+            assert_eq!(<&u16>::try_write_le(&value, &mut bytes), Ok(2));
+            assert_eq!(bytes, [0xbb, 0xaa]);
+            assert_eq!(<&u16>::try_write_be(&value, &mut bytes), Ok(2));
+            assert_eq!(bytes, [0xaa, 0xbb]);
+        }
+    }
+
+    mod option {
+        use super::*;
+
+        #[test]
+        fn should_write_none() {
+            let value: Option<u32> = None;
+            let mut bytes = [0xff; 2];
+
+            assert_eq!(value.get_size(), 1);
+            assert_eq!(value.try_write_le(&mut bytes), Ok(1));
+            assert_eq!(bytes, [0x00, 0xff]);
+        }
+
+        #[test]
+        fn should_write_some() {
+            let value = Some(0xaabbu16);
+            let mut bytes = [0; 3];
+
+            assert_eq!(value.get_size(), 3);
+            assert_eq!(value.try_write_be(&mut bytes), Ok(3));
+            assert_eq!(bytes, [0x01, 0xaa, 0xbb]);
+        }
+
+        #[test]
+        fn should_return_error_with_the_value_offset() {
+            let mut bytes = [0; 3];
+            let error = Some(0xaabbccddu32).try_write_le(&mut bytes).unwrap_err();
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 1,
+                    data_len: 3,
+                }
+            );
+        }
+    }
+
+    mod tuples {
+        use super::*;
+
+        #[test]
+        fn should_write_le_elements_in_order() {
+            let mut bytes = [0xff; 8];
+            let value: (u8, u16, u32) = (1, 2, 3);
+
+            assert_eq!(value.get_size(), 7);
+            assert_eq!(value.try_write_le(&mut bytes), Ok(7));
+            assert_eq!(bytes, [0x01, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0xff]);
+        }
+
+        #[test]
+        fn should_write_be_elements_in_order() {
+            let mut bytes = [0; 7];
+            let value: (u8, u16, u32) = (1, 2, 3);
+
+            assert_eq!(value.try_write_be(&mut bytes), Ok(7));
+            assert_eq!(bytes, [0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03]);
+        }
+
+        #[test]
+        fn should_return_error_with_the_failed_element_offset() {
+            let mut bytes = [0; 4];
+            let value: (u8, u16, u32) = (1, 2, 3);
+
+            assert_eq!(
+                value.try_write_le(&mut bytes),
+                Err(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 3,
+                    data_len: 4,
+                })
+            );
+        }
+    }
+}
@@ -1,9 +1,17 @@
 use snafu::Snafu;
 
+/// The error type for reads and writes.
+///
+/// [core::fmt::Display] is always implemented, and `std::error::Error`
+/// is implemented when the `std` feature is enabled.
+/// `Serialize` and `Deserialize` are implemented when the `serde` feature is enabled.
+/// Messages are `&'static str`, so errors can only be deserialized from `'static` input,
+/// such as JSON in a `&'static str`.
 #[derive(Debug, PartialEq, Snafu)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     #[snafu(display(
-        "Invalid size: wanted 0x{:x} at offset offset: 0x{:x}, but data length is 0x{:x} ",
+        "Invalid size: wanted 0x{:x} at offset 0x{:x}, but data length is 0x{:x}",
         wanted_size,
         offset,
         data_len
@@ -14,16 +22,21 @@ pub enum Error {
         data_len: usize,
     },
     #[snafu(display(
-        "Invalid alignment: wanted size: {}, source size: {}, source offset: {}",
+        "Invalid alignment: wanted size: {}, source size: {}, source offset: {}, alignment: {}",
         wanted_size,
         source_size,
-        source_offset
+        source_offset,
+        alignment
     ))]
     InvalidAlignment {
         wanted_size: usize,
         source_size: usize,
         source_offset: usize,
+        alignment: usize,
     },
+    /// A string was not valid UTF-8, starting at the offset.
+    #[snafu(display("Invalid UTF-8 at offset: 0x{:x}", offset))]
+    InvalidUtf8 { offset: usize },
     /// Generic read error message to describe a custom read error by the implementor.
     #[snafu(display("Invalid read: {}", message))]
     InvalidRead { message: &'static str },
@@ -48,6 +61,174 @@ pub(crate) fn add_error_context<T>(
             offset: offset + error_offset,
             data_len,
         },
+        Error::InvalidUtf8 {
+            offset: error_offset,
+        } => Error::InvalidUtf8 {
+            offset: offset + error_offset,
+        },
         _ => error,
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    mod add_error_context {
+        use super::*;
+
+        #[test]
+        fn should_shift_size_offsets() {
+            let error: Result<(), Error> = Err(Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            });
+
+            assert_eq!(
+                add_error_context(error, 6, 10),
+                Err(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 8,
+                    data_len: 10,
+                })
+            );
+        }
+
+        #[test]
+        fn should_shift_utf8_offsets() {
+            let error: Result<(), Error> = Err(Error::InvalidUtf8 { offset: 2 });
+            assert_eq!(
+                add_error_context(error, 6, 10),
+                Err(Error::InvalidUtf8 { offset: 8 })
+            );
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn should_include_size_fields() {
+            let error = Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Invalid size: wanted 0x4 at offset 0x6, but data length is 0x8"
+            );
+        }
+
+        #[test]
+        fn should_include_alignment_fields() {
+            let error = Error::InvalidAlignment {
+                wanted_size: 4,
+                source_size: 4,
+                source_offset: 3,
+                alignment: 4,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Invalid alignment: wanted size: 4, source size: 4, source offset: 3, alignment: 4"
+            );
+        }
+
+        #[test]
+        fn should_include_utf8_offset() {
+            let error = Error::InvalidUtf8 { offset: 10 };
+            assert_eq!(error.to_string(), "Invalid UTF-8 at offset: 0xa");
+        }
+
+        #[test]
+        fn should_include_messages() {
+            let read_error = Error::InvalidRead {
+                message: "Bad read",
+            };
+            let write_error = Error::InvalidWrite {
+                message: "Bad write",
+            };
+
+            assert_eq!(read_error.to_string(), "Invalid read: Bad read");
+            assert_eq!(write_error.to_string(), "Invalid write: Bad write");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod std_error {
+        use super::*;
+
+        extern crate std;
+        use std::{boxed::Box, error::Error as StdError};
+
+        #[test]
+        fn should_box_as_a_std_error() {
+            let error: Box<dyn StdError> = Box::new(Error::InvalidRead {
+                message: "Bad read",
+            });
+            assert_eq!(error.to_string(), "Invalid read: Bad read");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+
+        // Messages are borrowed as `&'static str`, so deserialize from static JSON.
+        fn assert_round_trip(error: Error, json: &'static str) {
+            assert_eq!(serde_json::to_string(&error).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Error>(json).unwrap(), error);
+        }
+
+        #[test]
+        fn should_round_trip_invalid_size() {
+            assert_round_trip(
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                },
+                r#"{"InvalidSize":{"wanted_size":4,"offset":6,"data_len":8}}"#,
+            );
+        }
+
+        #[test]
+        fn should_round_trip_invalid_alignment() {
+            assert_round_trip(
+                Error::InvalidAlignment {
+                    wanted_size: 4,
+                    source_size: 4,
+                    source_offset: 3,
+                    alignment: 4,
+                },
+                r#"{"InvalidAlignment":{"wanted_size":4,"source_size":4,"source_offset":3,"alignment":4}}"#,
+            );
+        }
+
+        #[test]
+        fn should_round_trip_invalid_utf8() {
+            assert_round_trip(
+                Error::InvalidUtf8 { offset: 10 },
+                r#"{"InvalidUtf8":{"offset":10}}"#,
+            );
+        }
+
+        #[test]
+        fn should_round_trip_messages() {
+            assert_round_trip(
+                Error::InvalidRead {
+                    message: "Bad read",
+                },
+                r#"{"InvalidRead":{"message":"Bad read"}}"#,
+            );
+            assert_round_trip(
+                Error::InvalidWrite {
+                    message: "Bad write",
+                },
+                r#"{"InvalidWrite":{"message":"Bad write"}}"#,
+            );
+        }
+    }
+}
@@ -3,6 +3,9 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod reader;
 pub use reader::*;
 
@@ -18,4 +21,14 @@ pub use endian::*;
 mod stream;
 pub use stream::*;
 
-pub use macros::*;
+mod destructure;
+
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+pub use diff::*;
+
+pub use macros::{EndianRead, EndianWrite};
+
+#[cfg(feature = "alloc")]
+pub use macros::NonDefaultFields;
@@ -1,12 +1,31 @@
 #[cfg(feature = "alloc")]
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 
-use super::{add_error_context, EndianRead, Error, ReadOutput};
-use core::mem;
+use super::{
+    add_error_context, BitRead, BitReader, EndianRead, Endianness, Error, ReadOutput, SNorm16,
+    SNorm8, UNorm16, UNorm8,
+};
+use core::{array, convert::TryFrom, marker::PhantomData, mem, ops::RangeInclusive, slice::Chunks};
 use safe_transmute::{transmute_many_permissive, TriviallyTransmutable};
 
 pub type ReaderResult<T> = Result<T, Error>;
 
+/// A parser used by [Reader::read_versioned_le] and [Reader::read_versioned_be]
+/// to read the data following a version field.
+pub type VersionedRead<R, T> = fn(&R, usize) -> ReaderResult<T>;
+
+/// The size of the buffer [Reader::read_le_at_bit] shifts unaligned values into.
+const BIT_READ_BUFFER_SIZE: usize = 16;
+
+struct AssertSize<T, const EXPECTED: usize>(PhantomData<T>);
+
+impl<T, const EXPECTED: usize> AssertSize<T, EXPECTED> {
+    const ASSERT: () = assert!(
+        mem::size_of::<T>() == EXPECTED,
+        "The size of the type does not match the expected size"
+    );
+}
+
 /// An interface to safely read values from a source.
 pub trait Reader {
     /// Returns the data to be read from.
@@ -32,17 +51,27 @@ pub trait Reader {
     #[inline(always)]
     fn get_slice_of_size(&self, offset: usize, size: usize) -> ReaderResult<&[u8]> {
         let data = self.get_slice();
-        let offset_end = offset + size;
 
-        if data.len() < offset_end {
-            return Err(Error::InvalidSize {
+        match offset.checked_add(size) {
+            Some(offset_end) if offset_end <= data.len() => Ok(&data[offset..offset_end]),
+            _ => Err(Error::InvalidSize {
                 wanted_size: size,
                 data_len: data.len(),
                 offset,
-            });
+            }),
         }
+    }
 
-        Ok(&data[offset..offset_end])
+    /// Same as [Reader::get_slice_of_size], but includes the number of bytes read,
+    /// so custom [EndianRead] implementations can return it.
+    #[inline(always)]
+    fn read_slice_with_output(
+        &self,
+        offset: usize,
+        size: usize,
+    ) -> ReaderResult<ReadOutput<&[u8]>> {
+        let slice = self.get_slice_of_size(offset, size)?;
+        Ok(ReadOutput::new(slice, size))
     }
 
     /// Same as [Reader::get_slice_of_size], but uses `T.len()` for the size.
@@ -50,17 +79,15 @@ pub trait Reader {
     fn get_sized_slice<T: Sized>(&self, offset: usize) -> ReaderResult<&[u8]> {
         let data = self.get_slice();
         let result_size = mem::size_of::<T>();
-        let offset_end = offset + result_size;
 
-        if data.len() < offset_end {
-            return Err(Error::InvalidSize {
+        match offset.checked_add(result_size) {
+            Some(offset_end) if offset_end <= data.len() => Ok(&data[offset..offset_end]),
+            _ => Err(Error::InvalidSize {
                 wanted_size: result_size,
                 data_len: data.len(),
                 offset,
-            });
+            }),
         }
-
-        Ok(&data[offset..offset_end])
     }
 
     /// Safely gets a [TriviallyTransmutable] reference.
@@ -77,6 +104,7 @@ pub trait Reader {
                 wanted_size: mem::size_of::<T>(),
                 source_size: bytes.len(),
                 source_offset: offset,
+                alignment: mem::align_of::<T>(),
             })?;
 
         // If we get here we're guaranteed to have one value (and only one)
@@ -90,12 +118,105 @@ pub trait Reader {
         Ok(*self.get_transmutable(offset)?)
     }
 
+    /// Same as [Reader::read], but fails to compile if the size of `T` isn't `EXPECTED`,
+    /// which guards against a type's layout changing without the data format changing.
+    ///
+    /// ```compile_fail,E0080
+    /// use no_std_io::Reader;
+    ///
+    /// let bytes = [0; 8];
+    /// let value = bytes.read_exact_size::<u32, 8>(0);
+    /// ```
+    #[inline(always)]
+    fn read_exact_size<T: TriviallyTransmutable, const EXPECTED: usize>(
+        &self,
+        offset: usize,
+    ) -> ReaderResult<T> {
+        // Referencing the constant makes the assertion run at compile time
+        #[allow(clippy::let_unit_value)]
+        let _ = AssertSize::<T, EXPECTED>::ASSERT;
+        self.read(offset)
+    }
+
+    /// Reads `N` [TriviallyTransmutable] values at once in native endianness.
+    ///
+    /// Like [Reader::get_transmutable], an error is returned if the offset
+    /// does not have enough data for all of the values or is unaligned.
+    #[inline(always)]
+    fn read_transmutable_array<const N: usize, T: TriviallyTransmutable>(
+        &self,
+        offset: usize,
+    ) -> ReaderResult<[T; N]> {
+        let size = mem::size_of::<T>()
+            .checked_mul(N)
+            .ok_or_else(|| Error::InvalidSize {
+                wanted_size: usize::MAX,
+                offset,
+                data_len: self.get_slice().len(),
+            })?;
+        let bytes = self.get_slice_of_size(offset, size)?;
+
+        let values =
+            transmute_many_permissive::<T>(bytes).map_err(|_| Error::InvalidAlignment {
+                wanted_size: size,
+                source_size: bytes.len(),
+                source_offset: offset,
+                alignment: mem::align_of::<T>(),
+            })?;
+
+        // The slice has exactly `N` values, so this can't fail
+        Ok(<[T; N]>::try_from(values).unwrap())
+    }
+
+    /// Same as [Reader::get_transmutable], but copies the bytes into an aligned position of a
+    /// caller-provided scratch buffer first, so the offset does not need to be aligned.
+    ///
+    /// No allocation or stack temporary is used. The scratch buffer needs room for the value
+    /// plus up to `align_of::<T>() - 1` bytes of padding,
+    /// otherwise [Error::InvalidRead] is returned.
+    #[inline(always)]
+    fn read_packed_into<'a, T: TriviallyTransmutable>(
+        &self,
+        offset: usize,
+        scratch: &'a mut [u8],
+    ) -> ReaderResult<&'a T> {
+        let bytes = self.get_sized_slice::<T>(offset)?;
+        let size = bytes.len();
+        let start = scratch.as_ptr().align_offset(mem::align_of::<T>());
+
+        let aligned = match start.checked_add(size) {
+            Some(end) if end <= scratch.len() => &mut scratch[start..end],
+            _ => {
+                return Err(Error::InvalidRead {
+                    message: "Scratch buffer is too small",
+                })
+            }
+        };
+        aligned.copy_from_slice(bytes);
+
+        let read_value =
+            transmute_many_permissive::<T>(aligned).map_err(|_| Error::InvalidAlignment {
+                wanted_size: size,
+                source_size: aligned.len(),
+                source_offset: offset,
+                alignment: mem::align_of::<T>(),
+            })?;
+
+        Ok(read_value.first().unwrap())
+    }
+
     /// Same as [Reader::read], but returns a default value if the read is invalid.
     #[inline(always)]
     fn default_read<T: TriviallyTransmutable + Default>(&self, offset: usize) -> T {
         self.read(offset).unwrap_or_default()
     }
 
+    /// Same as [Reader::read], but returns [None] if the read is invalid.
+    #[inline(always)]
+    fn checked_read<T: TriviallyTransmutable>(&self, offset: usize) -> Option<T> {
+        self.read(offset).ok()
+    }
+
     /// Reads a value from its little endian representation.
     ///
     /// Prefer endian agnostic methods when possible.
@@ -128,6 +249,79 @@ pub trait Reader {
         self.read_le(offset).unwrap_or_default()
     }
 
+    /// Same as [Reader::read_le], but returns the fallback value if the read is invalid.
+    #[inline(always)]
+    fn read_le_or<T: EndianRead>(&self, offset: usize, fallback: T) -> T {
+        self.read_le(offset).unwrap_or(fallback)
+    }
+
+    /// Same as [Reader::read_le], but returns [None] if the read is invalid.
+    #[inline(always)]
+    fn checked_read_le<T: EndianRead>(&self, offset: usize) -> Option<T> {
+        self.read_le(offset).ok()
+    }
+
+    /// Same as [Reader::read_le], but reads from a bit offset instead of a byte offset.
+    /// Bits are numbered from the least significant bit of each byte.
+    ///
+    /// Values read from an unaligned bit offset are shifted into a stack buffer,
+    /// so they must be at most 16 bytes.
+    /// The offset, size, and data length of an [Error::InvalidSize] are in bits.
+    #[inline(always)]
+    fn read_le_at_bit<T: EndianRead>(&self, bit_offset: usize) -> ReaderResult<T> {
+        let data = self.get_slice();
+        let data_bits = data.len().checked_mul(8).ok_or(Error::InvalidRead {
+            message: "Data is too large to address in bits",
+        })?;
+
+        if bit_offset > data_bits {
+            return Err(Error::InvalidSize {
+                wanted_size: 0,
+                offset: bit_offset,
+                data_len: data_bits,
+            });
+        }
+
+        let bytes = &data[bit_offset / 8..];
+        let result = match bit_offset % 8 {
+            0 => T::try_read_le(bytes),
+            shift => {
+                let size = match T::FIXED_SIZE {
+                    Some(size) if size > BIT_READ_BUFFER_SIZE => {
+                        return Err(Error::InvalidRead {
+                            message: "Value is too large to read from a bit offset",
+                        })
+                    }
+                    Some(size) => size,
+                    None => BIT_READ_BUFFER_SIZE,
+                };
+                let mut buffer = [0; BIT_READ_BUFFER_SIZE];
+                let shifted = &mut buffer[..size.min(bytes.len().saturating_sub(1))];
+
+                for (byte, pair) in shifted.iter_mut().zip(bytes.windows(2)) {
+                    *byte = (pair[0] >> shift) | (pair[1] << (8 - shift));
+                }
+
+                T::try_read_le(shifted)
+            }
+        };
+
+        result
+            .map(|output| output.into_data())
+            .map_err(|error| match error {
+                Error::InvalidSize {
+                    wanted_size,
+                    offset,
+                    ..
+                } => Error::InvalidSize {
+                    wanted_size: wanted_size.saturating_mul(8),
+                    offset: bit_offset.saturating_add(offset.saturating_mul(8)),
+                    data_len: data_bits,
+                },
+                error => error,
+            })
+    }
+
     /// Reads a value from its big endian representation.
     ///
     /// Prefer endian agnostic methods when possible.
@@ -160,6 +354,370 @@ pub trait Reader {
         self.read_be(offset).unwrap_or_default()
     }
 
+    /// Same as [Reader::read_be], but returns the fallback value if the read is invalid.
+    #[inline(always)]
+    fn read_be_or<T: EndianRead>(&self, offset: usize, fallback: T) -> T {
+        self.read_be(offset).unwrap_or(fallback)
+    }
+
+    /// Same as [Reader::read_be], but returns [None] if the read is invalid.
+    #[inline(always)]
+    fn checked_read_be<T: EndianRead>(&self, offset: usize) -> Option<T> {
+        self.read_be(offset).ok()
+    }
+
+    /// Same as [Reader::read_le], but returns [Error::InvalidRead] if the value is outside of the range.
+    #[inline(always)]
+    fn read_le_in_range<T: EndianRead + PartialOrd>(
+        &self,
+        offset: usize,
+        range: RangeInclusive<T>,
+    ) -> ReaderResult<T> {
+        let value = self.read_le(offset)?;
+        match range.contains(&value) {
+            true => Ok(value),
+            false => Err(Error::InvalidRead {
+                message: "Value out of range",
+            }),
+        }
+    }
+
+    /// Same as [Reader::read_be], but returns [Error::InvalidRead] if the value is outside of the range.
+    #[inline(always)]
+    fn read_be_in_range<T: EndianRead + PartialOrd>(
+        &self,
+        offset: usize,
+        range: RangeInclusive<T>,
+    ) -> ReaderResult<T> {
+        let value = self.read_be(offset)?;
+        match range.contains(&value) {
+            true => Ok(value),
+            false => Err(Error::InvalidRead {
+                message: "Value out of range",
+            }),
+        }
+    }
+
+    /// Reads a value in the endianness selected by the [Endianness] marker type.
+    ///
+    /// This is resolved at compile time, so generic parsers can be written once
+    /// and used for both little endian and big endian data.
+    #[inline(always)]
+    fn read_with_endianness<E: Endianness, T: EndianRead>(&self, offset: usize) -> ReaderResult<T> {
+        let bytes = self.get_slice_at_offset(offset);
+        let result = add_error_context(E::try_read::<T>(bytes), offset, self.get_slice().len())?;
+        Ok(result.into_data())
+    }
+
+    /// Reads a little endian version field at the offset, then calls the parser paired with the
+    /// matching version at the offset following the version field.
+    ///
+    /// Returns [Error::InvalidRead] if no parser is registered for the version.
+    #[inline(always)]
+    fn read_versioned_le<V: EndianRead + Eq, T>(
+        &self,
+        offset: usize,
+        versions: &[(V, VersionedRead<Self, T>)],
+    ) -> ReaderResult<T> {
+        let version = self.read_le_with_output::<V>(offset)?;
+        let data_offset = offset + version.get_read_bytes();
+        let version = version.into_data();
+
+        match versions.iter().find(|(candidate, _)| *candidate == version) {
+            Some((_, parse)) => parse(self, data_offset),
+            None => Err(Error::InvalidRead {
+                message: "Unknown version",
+            }),
+        }
+    }
+
+    /// Same as [Reader::read_versioned_le], but reads the version field as big endian.
+    #[inline(always)]
+    fn read_versioned_be<V: EndianRead + Eq, T>(
+        &self,
+        offset: usize,
+        versions: &[(V, VersionedRead<Self, T>)],
+    ) -> ReaderResult<T> {
+        let version = self.read_be_with_output::<V>(offset)?;
+        let data_offset = offset + version.get_read_bytes();
+        let version = version.into_data();
+
+        match versions.iter().find(|(candidate, _)| *candidate == version) {
+            Some((_, parse)) => parse(self, data_offset),
+            None => Err(Error::InvalidRead {
+                message: "Unknown version",
+            }),
+        }
+    }
+
+    /// Returns the bytes from the offset up to, but not including, the first `delimiter`.
+    /// The read bytes of the output include the delimiter so the output can be used to advance past it.
+    ///
+    /// Returns [Error::InvalidRead] if the delimiter is not found before the end of the data.
+    #[inline(always)]
+    fn read_until_byte(&self, offset: usize, delimiter: u8) -> ReaderResult<ReadOutput<&[u8]>> {
+        let bytes = self.get_slice_at_offset(offset);
+
+        match bytes.iter().position(|byte| *byte == delimiter) {
+            Some(length) => Ok(ReadOutput::new(&bytes[..length], length + 1)),
+            None => Err(Error::InvalidRead {
+                message: "Delimiter not found",
+            }),
+        }
+    }
+
+    /// Reads a null terminated UTF-8 string from the offset.
+    /// The returned string does not include the null terminator.
+    ///
+    /// Returns [Error::InvalidRead] if no null terminator is found before the end of the data
+    /// or [Error::InvalidUtf8] if the string is not valid UTF-8.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_cstr(&self, offset: usize) -> ReaderResult<String> {
+        let bytes = self.read_until_byte(offset, 0)?.into_data();
+        String::from_utf8(bytes.to_vec()).map_err(|error| Error::InvalidUtf8 {
+            offset: offset + error.utf8_error().valid_up_to(),
+        })
+    }
+
+    /// Reads a Pascal string from the offset, which is a [u8] length followed by that many UTF-8 bytes.
+    /// The read bytes of the output include the length byte.
+    ///
+    /// Returns [Error::InvalidUtf8] if the string is not valid UTF-8.
+    #[inline(always)]
+    fn read_pascal_str(&self, offset: usize) -> ReaderResult<ReadOutput<&str>> {
+        let length = usize::from(self.read_le::<u8>(offset)?);
+        let bytes = self.get_slice_of_size(offset + 1, length)?;
+        let value = core::str::from_utf8(bytes).map_err(|error| Error::InvalidUtf8 {
+            offset: offset + 1 + error.valid_up_to(),
+        })?;
+        Ok(ReadOutput::new(value, length + 1))
+    }
+
+    /// Reads a byte from the offset as a bitmask of 8 flags,
+    /// where the first flag is the least significant bit.
+    #[inline(always)]
+    fn read_bool8(&self, offset: usize) -> ReaderResult<[bool; 8]> {
+        let mask = self.read_le::<u8>(offset)?;
+        Ok(core::array::from_fn(|bit| mask & (1 << bit) != 0))
+    }
+
+    /// Reads `size` bytes from the offset and converts them with the target type's [TryFrom] implementation.
+    ///
+    /// Returns [Error::InvalidRead] if the conversion fails.
+    #[inline(always)]
+    fn read_try_from<T>(&self, offset: usize, size: usize) -> ReaderResult<T>
+    where
+        T: for<'a> TryFrom<&'a [u8]>,
+    {
+        let bytes = self.get_slice_of_size(offset, size)?;
+        T::try_from(bytes).map_err(|_| Error::InvalidRead {
+            message: "Invalid conversion",
+        })
+    }
+
+    /// Reads a little endian two's complement integer that is `byte_width` bytes wide
+    /// and sign extends it into an [i64].
+    ///
+    /// Returns [Error::InvalidRead] if `byte_width` is 0 or greater than 8.
+    #[inline(always)]
+    fn read_signed_le(&self, offset: usize, byte_width: usize) -> ReaderResult<i64> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidRead {
+                message: "Invalid byte width",
+            });
+        }
+
+        let mut bytes = [0; 8];
+        bytes[..byte_width].copy_from_slice(self.get_slice_of_size(offset, byte_width)?);
+
+        let shift = 64 - (byte_width * 8);
+        Ok((i64::from_le_bytes(bytes) << shift) >> shift)
+    }
+
+    /// Same as [Reader::read_signed_le], but reads a big endian integer.
+    #[inline(always)]
+    fn read_signed_be(&self, offset: usize, byte_width: usize) -> ReaderResult<i64> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidRead {
+                message: "Invalid byte width",
+            });
+        }
+
+        let mut bytes = [0; 8];
+        bytes[8 - byte_width..].copy_from_slice(self.get_slice_of_size(offset, byte_width)?);
+
+        let shift = 64 - (byte_width * 8);
+        Ok(i64::from_be_bytes(bytes) << shift >> shift)
+    }
+
+    /// Reads a little endian unsigned integer that is `byte_width` bytes wide
+    /// and zero extends it into a [u64].
+    ///
+    /// Returns [Error::InvalidRead] if `byte_width` is 0 or greater than 8.
+    #[inline(always)]
+    fn read_unsigned_le(&self, offset: usize, byte_width: usize) -> ReaderResult<u64> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidRead {
+                message: "Invalid byte width",
+            });
+        }
+
+        let mut bytes = [0; 8];
+        bytes[..byte_width].copy_from_slice(self.get_slice_of_size(offset, byte_width)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Same as [Reader::read_unsigned_le], but reads a big endian integer.
+    #[inline(always)]
+    fn read_unsigned_be(&self, offset: usize, byte_width: usize) -> ReaderResult<u64> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidRead {
+                message: "Invalid byte width",
+            });
+        }
+
+        let mut bytes = [0; 8];
+        bytes[8 - byte_width..].copy_from_slice(self.get_slice_of_size(offset, byte_width)?);
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Reads a [UNorm8] and returns its value from 0.0 to 1.0.
+    #[inline(always)]
+    fn read_unorm8(&self, offset: usize) -> ReaderResult<f32> {
+        Ok(self.read_le::<UNorm8>(offset)?.0)
+    }
+
+    /// Reads a [SNorm8] and returns its value from -1.0 to 1.0.
+    #[inline(always)]
+    fn read_snorm8(&self, offset: usize) -> ReaderResult<f32> {
+        Ok(self.read_le::<SNorm8>(offset)?.0)
+    }
+
+    /// Reads a little endian [UNorm16] and returns its value from 0.0 to 1.0.
+    #[inline(always)]
+    fn read_unorm16_le(&self, offset: usize) -> ReaderResult<f32> {
+        Ok(self.read_le::<UNorm16>(offset)?.0)
+    }
+
+    /// Same as [Reader::read_unorm16_le], but reads a big endian value.
+    #[inline(always)]
+    fn read_unorm16_be(&self, offset: usize) -> ReaderResult<f32> {
+        Ok(self.read_be::<UNorm16>(offset)?.0)
+    }
+
+    /// Reads a little endian [SNorm16] and returns its value from -1.0 to 1.0.
+    #[inline(always)]
+    fn read_snorm16_le(&self, offset: usize) -> ReaderResult<f32> {
+        Ok(self.read_le::<SNorm16>(offset)?.0)
+    }
+
+    /// Same as [Reader::read_snorm16_le], but reads a big endian value.
+    #[inline(always)]
+    fn read_snorm16_be(&self, offset: usize) -> ReaderResult<f32> {
+        Ok(self.read_be::<SNorm16>(offset)?.0)
+    }
+
+    /// Reads `count` little endian values one after another starting at the offset.
+    /// Returns the values and the total number of bytes read.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_struct_vec_le<T: EndianRead>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> ReaderResult<(Vec<T>, usize)> {
+        read_struct_vec(self, offset, count, Self::read_le_with_output)
+    }
+
+    /// Same as [Reader::read_struct_vec_le], but reads big endian values.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_struct_vec_be<T: EndianRead>(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> ReaderResult<(Vec<T>, usize)> {
+        read_struct_vec(self, offset, count, Self::read_be_with_output)
+    }
+
+    /// Reads little endian records starting at the offset until the end of the data
+    /// or until `CAP` records have been read, without allocating.
+    /// Returns the records and the number of records read.
+    ///
+    /// An error is returned if a record is only partially available.
+    #[inline(always)]
+    fn read_records_le<const CAP: usize, T: EndianRead>(
+        &self,
+        offset: usize,
+    ) -> ReaderResult<([Option<T>; CAP], usize)> {
+        let mut records = [(); CAP].map(|_| None);
+        let mut record_offset = offset;
+        let mut count = 0;
+
+        while count < CAP && record_offset < self.get_slice().len() {
+            let record = self.read_le_with_output::<T>(record_offset)?;
+            record_offset += record.get_read_bytes();
+            records[count] = Some(record.into_data());
+            count += 1;
+        }
+
+        Ok((records, count))
+    }
+
+    /// Same as [Reader::read_records_le], but reads big endian records.
+    #[inline(always)]
+    fn read_records_be<const CAP: usize, T: EndianRead>(
+        &self,
+        offset: usize,
+    ) -> ReaderResult<([Option<T>; CAP], usize)> {
+        let mut records = [(); CAP].map(|_| None);
+        let mut record_offset = offset;
+        let mut count = 0;
+
+        while count < CAP && record_offset < self.get_slice().len() {
+            let record = self.read_be_with_output::<T>(record_offset)?;
+            record_offset += record.get_read_bytes();
+            records[count] = Some(record.into_data());
+            count += 1;
+        }
+
+        Ok((records, count))
+    }
+
+    /// Reads `SIZE` bit-packed values that follow each other with no padding,
+    /// so every value after the first can start in the middle of a byte.
+    /// Bits are read from the most significant bit of each byte first, starting at the offset.
+    ///
+    /// The offset, size, and data length of an [Error::InvalidSize] are in bits from the offset.
+    #[inline(always)]
+    fn read_bit_array<const SIZE: usize, T: BitRead>(
+        &self,
+        offset: usize,
+    ) -> ReaderResult<[T; SIZE]> {
+        let mut reader = BitReader::new(self.get_slice_at_offset(offset));
+        let mut error = None;
+        let values: [Option<T>; SIZE] = array::from_fn(|_| {
+            if error.is_some() {
+                return None;
+            }
+
+            match T::try_read_bits(&mut reader) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    error = Some(err);
+                    None
+                }
+            }
+        });
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(values.map(Option::unwrap))
+    }
+
     /// Same as [Reader::get_slice_of_size], but converts the result to a vector.
     #[cfg(feature = "alloc")]
     #[inline(always)]
@@ -175,6 +733,62 @@ pub trait Reader {
         self.read_byte_vec(offset, size)
             .unwrap_or_else(|_| vec![0; size])
     }
+
+    /// Returns an iterator over `size` byte chunks of the data.
+    /// The last chunk is shorter if the data length isn't a multiple of `size`.
+    ///
+    /// Panics if `size` is 0.
+    #[inline(always)]
+    fn chunks(&self, size: usize) -> Chunks<'_, u8> {
+        self.get_slice().chunks(size)
+    }
+
+    /// Returns a [SubReader] over `len` bytes starting at `base`.
+    /// Offsets given to the [SubReader] are relative to `base`.
+    ///
+    /// An error is returned if the window exceeds the available data.
+    #[inline(always)]
+    fn sub_reader(&self, base: usize, len: usize) -> ReaderResult<SubReader<'_>> {
+        let data = self.get_slice_of_size(base, len)?;
+        Ok(SubReader { data, base })
+    }
+}
+
+/// Reads `count` values one after another with `read`, like [Reader::read_struct_vec_le].
+///
+/// The capacity is bounded by the data after the offset so a large count
+/// can't reserve more memory than the data could fill.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn read_struct_vec<R: Reader + ?Sized, T: EndianRead>(
+    reader: &R,
+    offset: usize,
+    count: usize,
+    read: fn(&R, usize) -> ReaderResult<ReadOutput<T>>,
+) -> ReaderResult<(Vec<T>, usize)> {
+    let data_len = reader.get_slice().len();
+    let remaining = data_len.saturating_sub(offset);
+    let max_count = match T::FIXED_SIZE {
+        Some(size) if size > 0 => remaining / size,
+        _ => remaining,
+    };
+    let mut values = Vec::with_capacity(count.min(max_count));
+    let mut value_offset = offset;
+
+    for _ in 0..count {
+        let value = read(reader, value_offset)?;
+        let value_size = value.get_read_bytes();
+        value_offset = value_offset
+            .checked_add(value_size)
+            .ok_or(Error::InvalidSize {
+                wanted_size: value_size,
+                offset: value_offset,
+                data_len,
+            })?;
+        values.push(value.into_data());
+    }
+
+    Ok((values, value_offset - offset))
 }
 
 impl<const SIZE: usize> Reader for [u8; SIZE] {
@@ -206,6 +820,54 @@ impl Reader for Vec<u8> {
     }
 }
 
+/// A window of a parent [Reader], created by [Reader::sub_reader].
+///
+/// Reads past the end of the window return an error using the window length,
+/// even if the parent has more data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubReader<'a> {
+    data: &'a [u8],
+    base: usize,
+}
+
+impl<'a> SubReader<'a> {
+    /// Returns the offset of the window in the parent reader.
+    #[inline(always)]
+    pub fn get_base(&self) -> usize {
+        self.base
+    }
+}
+
+impl<'a> Reader for SubReader<'a> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        self.data
+    }
+}
+
+/// An object-safe interface to a [Reader]'s data, so readers can be stored as trait objects.
+///
+/// This is implemented for every [Reader], and `&dyn DynReader` implements [Reader],
+/// so all [Reader] methods can still be used on a trait object.
+pub trait DynReader {
+    /// Same as [Reader::get_slice].
+    fn get_dyn_slice(&self) -> &[u8];
+}
+
+impl<T: Reader + ?Sized> DynReader for T {
+    #[inline(always)]
+    fn get_dyn_slice(&self) -> &[u8] {
+        self.get_slice()
+    }
+}
+
+impl Reader for &dyn DynReader {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        (**self).get_dyn_slice()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -229,6 +891,23 @@ mod test {
     mod get_slice_of_size {
         use super::*;
 
+        #[test]
+        fn should_return_error_if_offset_overflows() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = reader
+                .get_slice_of_size(usize::MAX, 4)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: usize::MAX,
+                    data_len: 8,
+                }
+            );
+        }
+
         #[test]
         fn should_return_a_slice_of_a_given_size() {
             let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
@@ -258,25 +937,25 @@ mod test {
         }
     }
 
-    mod get_sized_slice {
+    mod read_slice_with_output {
         use super::*;
 
         #[test]
-        fn should_return_sized_slice() {
+        fn should_return_the_slice_and_the_size_as_read_bytes() {
             let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let slice = reader
-                .get_sized_slice::<u32>(4)
+            let output = reader
+                .read_slice_with_output(2, 3)
                 .expect("Read should have been successful.");
 
-            let result = [5, 6, 7, 8];
-            assert_eq!(slice, result);
+            assert_eq!(output.get_read_bytes(), 3);
+            assert_eq!(output.into_data(), [3, 4, 5]);
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let error = reader
-                .get_sized_slice::<u32>(6)
+                .read_slice_with_output(6, 4)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
@@ -290,12 +969,61 @@ mod test {
         }
     }
 
-    mod get_transmutable {
+    mod get_sized_slice {
         use super::*;
 
         #[test]
-        fn should_return_a_reference() {
-            let reader = MockReader::new(u64::to_ne_bytes(0x11223344aabbccdd));
+        fn should_return_error_if_offset_overflows() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = reader
+                .get_sized_slice::<u32>(usize::MAX)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: usize::MAX,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_sized_slice() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let slice = reader
+                .get_sized_slice::<u32>(4)
+                .expect("Read should have been successful.");
+
+            let result = [5, 6, 7, 8];
+            assert_eq!(slice, result);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = reader
+                .get_sized_slice::<u32>(6)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod get_transmutable {
+        use super::*;
+
+        #[test]
+        fn should_return_a_reference() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x11223344aabbccdd));
             let slice = reader
                 .get_sized_slice::<u32>(4)
                 .expect("Read should have been successful.");
@@ -365,6 +1093,132 @@ mod test {
                     wanted_size: 4,
                     source_size: 4,
                     source_offset: 3,
+                    alignment: 4,
+                }
+            );
+        }
+    }
+
+    mod read_packed_into {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value_from_an_unaligned_offset() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334411223344));
+            let mut scratch = [0u8; 8];
+            let value = reader
+                .read_packed_into::<u32>(3, &mut scratch)
+                .expect("Read should have been successful.");
+
+            let mut expected = [0u8; 4];
+            expected.copy_from_slice(&reader.get_slice()[3..7]);
+            assert_eq!(*value, u32::from_ne_bytes(expected));
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334411223344));
+            let mut scratch = [0u8; 8];
+            let error = reader
+                .read_packed_into::<u32>(6, &mut scratch)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_scratch_is_too_small() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334411223344));
+            let mut scratch = [0u8; 2];
+            let error = reader
+                .read_packed_into::<u32>(0, &mut scratch)
+                .expect_err("Scratch should have been too small");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Scratch buffer is too small"
+                }
+            );
+        }
+    }
+
+    mod read_exact_size {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value_if_the_size_matches() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334411223344));
+            let value = reader
+                .read_exact_size::<u32, 4>(4)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0x11223344);
+        }
+    }
+
+    mod read_transmutable_array {
+        use super::*;
+
+        #[test]
+        fn should_return_values() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334455667788));
+            let values = reader
+                .read_transmutable_array::<2, u32>(0)
+                .expect("Read should have been successful.");
+
+            let expected = if cfg!(target_endian = "little") {
+                [0x55667788, 0x11223344]
+            } else {
+                [0x11223344, 0x55667788]
+            };
+            assert_eq!(values, expected);
+        }
+
+        #[test]
+        fn should_return_an_empty_array() {
+            let reader = MockReader::new([0; 8]);
+            assert_eq!(reader.read_transmutable_array::<0, u32>(8), Ok([]));
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334455667788));
+            let error = reader
+                .read_transmutable_array::<2, u32>(4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 4,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_alignment_is_invalid() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x1122334455667788));
+            let error = reader
+                .read_transmutable_array::<1, u32>(2)
+                .expect_err("Alignment should have been invalid");
+
+            assert_eq!(
+                error,
+                Error::InvalidAlignment {
+                    wanted_size: 4,
+                    source_size: 4,
+                    source_offset: 2,
+                    alignment: 4,
                 }
             );
         }
@@ -395,6 +1249,31 @@ mod test {
         }
     }
 
+    mod checked_read {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x11223344aabbccdd));
+            let value = reader.checked_read::<u32>(4);
+            assert_eq!(value, Some(0x11223344));
+        }
+
+        #[test]
+        fn should_return_none_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x11223344aabbccdd));
+            let value = reader.checked_read::<u32>(6);
+            assert_eq!(value, None);
+        }
+
+        #[test]
+        fn should_return_none_if_alignment_is_invalid() {
+            let reader = MockReader::new(u64::to_ne_bytes(0x11223344aabbccdd));
+            let value = reader.checked_read::<u32>(3);
+            assert_eq!(value, None);
+        }
+    }
+
     mod read_le_with_output {
         use super::*;
 
@@ -510,6 +1389,84 @@ mod test {
         }
     }
 
+    mod read_le_at_bit {
+        use super::*;
+
+        #[test]
+        fn should_read_from_a_byte_aligned_bit_offset() {
+            let reader = MockReader::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+            let value: u16 = reader.read_le_at_bit(8).expect("Read should have worked");
+            assert_eq!(value, 0x0302);
+        }
+
+        #[test]
+        fn should_read_from_an_unaligned_bit_offset() {
+            let reader = MockReader::new([0xf0, 0xab, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            let value: u16 = reader.read_le_at_bit(4).expect("Read should have worked");
+            assert_eq!(value, 0xcabf);
+        }
+
+        #[test]
+        fn should_read_the_last_bits() {
+            let reader = MockReader::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01]);
+            let value: u8 = reader.read_le_at_bit(55).expect("Read should have worked");
+            assert_eq!(value, 0x03);
+        }
+
+        #[test]
+        fn should_return_error_if_value_passes_the_end() {
+            let reader = MockReader::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+            let error = reader
+                .read_le_at_bit::<u16>(49)
+                .expect_err("Read should have failed");
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 16,
+                    offset: 49,
+                    data_len: 64,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_bit_offset_is_out_of_range() {
+            let reader = MockReader::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+            let error = reader
+                .read_le_at_bit::<u8>(65)
+                .expect_err("Read should have failed");
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 0,
+                    offset: 65,
+                    data_len: 64,
+                }
+            );
+        }
+
+        #[test]
+        fn should_read_the_largest_value_from_an_unaligned_bit_offset() {
+            let reader = [0xffu8; 17];
+            let value: u128 = reader.read_le_at_bit(3).expect("Read should have worked");
+            assert_eq!(value, u128::MAX);
+        }
+
+        #[test]
+        fn should_return_error_if_value_is_too_large_for_the_buffer() {
+            let reader = [0u8; 32];
+            let error = reader
+                .read_le_at_bit::<[u8; 17]>(1)
+                .expect_err("Read should have failed");
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Value is too large to read from a bit offset",
+                }
+            );
+        }
+    }
+
     mod default_read_le {
         use super::*;
 
@@ -528,6 +1485,42 @@ mod test {
         }
     }
 
+    mod read_le_or {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.read_le_or::<u32>(4, u32::MAX);
+            assert_eq!(value, 0xddccbbaa);
+        }
+
+        #[test]
+        fn should_return_fallback_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.read_le_or::<u32>(6, u32::MAX);
+            assert_eq!(value, u32::MAX);
+        }
+    }
+
+    mod checked_read_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.checked_read_le::<u32>(3);
+            assert_eq!(value, Some(0xccbbaa44));
+        }
+
+        #[test]
+        fn should_return_none_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.checked_read_le::<u32>(6);
+            assert_eq!(value, None);
+        }
+    }
+
     mod read_be_with_output {
         use super::*;
 
@@ -661,24 +1654,72 @@ mod test {
         }
     }
 
-    mod read_byte_vec {
+    mod read_be_or {
         use super::*;
 
         #[test]
         fn should_return_a_value() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            let value = reader
-                .read_byte_vec(4, 3)
-                .expect("Read should have been successful.");
+            let value = reader.read_be_or::<u32>(4, u32::MAX);
+            assert_eq!(value, 0xaabbccdd);
+        }
 
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+        #[test]
+        fn should_return_fallback_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.read_be_or::<u32>(6, u32::MAX);
+            assert_eq!(value, u32::MAX);
         }
+    }
+
+    mod checked_read_be {
+        use super::*;
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.checked_read_be::<u32>(3);
+            assert_eq!(value, Some(0x44aabbcc));
+        }
+
+        #[test]
+        fn should_return_none_if_size_is_too_large_for_offset() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.checked_read_be::<u32>(6);
+            assert_eq!(value, None);
+        }
+    }
+
+    mod read_le_in_range {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value_in_range() {
+            let reader = MockReader::new([0x00, 0x07, 0x00, 0x64, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_le_in_range::<u8>(1, 0..=7), Ok(7));
+            assert_eq!(reader.read_le_in_range::<u16>(3, 0..=100), Ok(100));
+        }
+
+        #[test]
+        fn should_return_error_if_value_is_out_of_range() {
+            let reader = MockReader::new([0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
             let error = reader
-                .read_byte_vec(6, 4)
+                .read_le_in_range::<u8>(1, 0..=7)
+                .expect_err("Value should have been out of range");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Value out of range"
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .read_le_in_range::<u32>(6, 0..=7)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
@@ -692,11 +1733,834 @@ mod test {
         }
     }
 
-    mod default_read_byte_vec {
+    mod read_be_in_range {
         use super::*;
 
         #[test]
-        fn should_return_a_value() {
+        fn should_return_a_value_in_range() {
+            let reader = MockReader::new([0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_be_in_range::<u16>(0, 0..=100), Ok(100));
+        }
+
+        #[test]
+        fn should_return_error_if_value_is_out_of_range() {
+            let reader = MockReader::new([0x00, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            let error = reader
+                .read_be_in_range::<u16>(0, 0..=100)
+                .expect_err("Value should have been out of range");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Value out of range"
+                }
+            );
+        }
+    }
+
+    mod read_with_endianness {
+        use super::*;
+        use crate::{BigEndian, LittleEndian};
+
+        #[test]
+        fn should_read_little_endian() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_with_endianness::<LittleEndian, u32>(4)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0xddccbbaa);
+        }
+
+        #[test]
+        fn should_read_big_endian() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_with_endianness::<BigEndian, u32>(4)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0xaabbccdd);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_with_endianness::<LittleEndian, u32>(6)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_versioned_le {
+        use super::*;
+
+        fn parse_v1(reader: &MockReader, offset: usize) -> ReaderResult<u32> {
+            Ok(reader.read_le::<u16>(offset)?.into())
+        }
+
+        fn parse_v2(reader: &MockReader, offset: usize) -> ReaderResult<u32> {
+            reader.read_le::<u32>(offset)
+        }
+
+        #[test]
+        fn should_dispatch_on_the_version() {
+            let reader = MockReader::new([0x02, 0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x00]);
+            let value = reader
+                .read_versioned_le::<u16, u32>(0, &[(1, parse_v1), (2, parse_v2)])
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0xddccbbaa);
+        }
+
+        #[test]
+        fn should_return_error_for_unknown_versions() {
+            let reader = MockReader::new([0x03, 0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x00]);
+            let error = reader
+                .read_versioned_le::<u16, u32>(0, &[(1, parse_v1), (2, parse_v2)])
+                .expect_err("Version should have been unknown");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Unknown version",
+                }
+            );
+        }
+    }
+
+    mod read_versioned_be {
+        use super::*;
+
+        fn parse_v1(reader: &MockReader, offset: usize) -> ReaderResult<u32> {
+            Ok(reader.read_be::<u16>(offset)?.into())
+        }
+
+        #[test]
+        fn should_dispatch_on_the_version() {
+            let reader = MockReader::new([0x00, 0x01, 0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x00]);
+            let value = reader
+                .read_versioned_be::<u16, u32>(0, &[(1, parse_v1)])
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0xaabb);
+        }
+    }
+
+    mod read_until_byte {
+        use super::*;
+
+        #[test]
+        fn should_return_bytes_before_the_delimiter() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x0a, 0xaa, 0x0a, 0xcc, 0xdd]);
+            let value = reader
+                .read_until_byte(1, 0x0a)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new([0x22, 0x33].as_slice(), 3));
+        }
+
+        #[test]
+        fn should_return_an_empty_slice_if_the_delimiter_is_first() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x0a, 0xaa, 0x0a, 0xcc, 0xdd]);
+            let value = reader
+                .read_until_byte(3, 0x0a)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new([].as_slice(), 1));
+        }
+
+        #[test]
+        fn should_return_error_if_delimiter_is_not_found() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x0a, 0xaa, 0x0a, 0xcc, 0xdd]);
+            let error = reader
+                .read_until_byte(6, 0x0a)
+                .expect_err("Delimiter should not have been found");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Delimiter not found",
+                }
+            );
+        }
+    }
+
+    mod read_cstr {
+        use super::*;
+
+        #[test]
+        fn should_return_a_string() {
+            let reader = MockReader::new([0x61, 0x62, 0x63, 0x00, 0x64, 0x65, 0x00, 0x66]);
+            let value = reader
+                .read_cstr(0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, "abc");
+        }
+
+        #[test]
+        fn should_stop_at_the_first_null() {
+            let reader = MockReader::new([0x61, 0x00, 0x63, 0x00, 0x64, 0x65, 0x00, 0x66]);
+            let value = reader
+                .read_cstr(0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, "a");
+        }
+
+        #[test]
+        fn should_return_error_if_terminator_is_missing() {
+            let reader = MockReader::new([0x61, 0x62, 0x63, 0x00, 0x64, 0x65, 0x00, 0x66]);
+            let error = reader
+                .read_cstr(7)
+                .expect_err("Terminator should have been missing");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Delimiter not found",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_string_is_invalid_utf8() {
+            let reader = MockReader::new([0x00, 0x61, 0xff, 0x63, 0x00, 0x64, 0x65, 0x00]);
+            let error = reader
+                .read_cstr(1)
+                .expect_err("String should have been invalid");
+
+            assert_eq!(error, Error::InvalidUtf8 { offset: 2 });
+        }
+    }
+
+    mod read_bool8 {
+        use super::*;
+
+        #[test]
+        fn should_expand_the_bits_lsb_first() {
+            let reader = MockReader::new([0, 0b1010_0001, 0, 0, 0, 0, 0, 0]);
+
+            assert_eq!(
+                reader.read_bool8(1),
+                Ok([true, false, false, false, false, true, false, true])
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_offset_is_too_large() {
+            let reader = MockReader::new([0; 8]);
+
+            assert_eq!(
+                reader.read_bool8(8),
+                Err(Error::InvalidSize {
+                    wanted_size: 1,
+                    offset: 8,
+                    data_len: 8,
+                })
+            );
+        }
+    }
+
+    mod read_pascal_str {
+        use super::*;
+
+        #[test]
+        fn should_return_a_string() {
+            let reader = MockReader::new([0xff, 0x03, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66]);
+            let value = reader
+                .read_pascal_str(1)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new("abc", 4));
+        }
+
+        #[test]
+        fn should_return_an_empty_string() {
+            let reader = MockReader::new([0x00, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67]);
+            let value = reader
+                .read_pascal_str(0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new("", 1));
+        }
+
+        #[test]
+        fn should_return_error_if_string_is_too_long_for_the_data() {
+            let reader = MockReader::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x61, 0x62]);
+            let error = reader
+                .read_pascal_str(5)
+                .expect_err("Length should have been too long");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 5,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_string_is_invalid_utf8() {
+            let reader = MockReader::new([0x00, 0x03, 0x61, 0xff, 0x63, 0x00, 0x00, 0x00]);
+            let error = reader
+                .read_pascal_str(1)
+                .expect_err("String should have been invalid");
+
+            assert_eq!(error, Error::InvalidUtf8 { offset: 3 });
+        }
+    }
+
+    mod read_try_from {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_try_from::<[u8; 3]>(4, 3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, [0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_return_error_if_conversion_fails() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_try_from::<[u8; 3]>(4, 2)
+                .expect_err("Conversion should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid conversion",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_try_from::<[u8; 4]>(6, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_signed_le {
+        use super::*;
+
+        #[test]
+        fn should_sign_extend_negative_values() {
+            let reader = MockReader::new([0xfe, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_signed_le(0, 3), Ok(-2));
+            assert_eq!(reader.read_signed_le(0, 4), Ok(-0x7f000002));
+            assert_eq!(reader.read_signed_le(3, 1), Ok(-128));
+        }
+
+        #[test]
+        fn should_not_sign_extend_positive_values() {
+            let reader = MockReader::new([0xff, 0xff, 0x7f, 0x80, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_signed_le(0, 3), Ok(0x7fffff));
+            assert_eq!(reader.read_signed_le(4, 4), Ok(0));
+        }
+
+        #[test]
+        fn should_read_widths_from_1_to_8() {
+            let reader = MockReader::new([0xff; 8]);
+            for byte_width in 1..=8 {
+                assert_eq!(reader.read_signed_le(0, byte_width), Ok(-1));
+            }
+
+            let reader = MockReader::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80]);
+            assert_eq!(reader.read_signed_le(0, 8), Ok(i64::MIN));
+            assert_eq!(reader.read_signed_le(2, 6), Ok(-0x800000000000));
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_widths() {
+            let reader = MockReader::new([0xff; 8]);
+
+            for byte_width in [0, 9] {
+                let error = reader
+                    .read_signed_le(0, byte_width)
+                    .expect_err("Byte width should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidRead {
+                        message: "Invalid byte width",
+                    }
+                );
+            }
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0xff; 8]);
+            let error = reader
+                .read_signed_le(6, 5)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 5,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_signed_be {
+        use super::*;
+
+        #[test]
+        fn should_sign_extend_negative_values() {
+            let reader = MockReader::new([0xff, 0xff, 0xfe, 0x80, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_signed_be(0, 3), Ok(-2));
+            assert_eq!(reader.read_signed_be(3, 5), Ok(-0x8000000000));
+        }
+
+        #[test]
+        fn should_not_sign_extend_positive_values() {
+            let reader = MockReader::new([0x7f, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x01]);
+            assert_eq!(reader.read_signed_be(0, 3), Ok(0x7fffff));
+            assert_eq!(reader.read_signed_be(3, 5), Ok(1));
+        }
+    }
+
+    mod read_unsigned_le {
+        use super::*;
+
+        #[test]
+        fn should_zero_extend_values() {
+            let reader = MockReader::new([0xfe, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_unsigned_le(0, 3), Ok(0xfffffe));
+            assert_eq!(reader.read_unsigned_le(1, 5), Ok(0x0080ffff));
+        }
+
+        #[test]
+        fn should_read_widths_from_1_to_8() {
+            let reader = MockReader::new([0xff; 8]);
+            for byte_width in 1..=8 {
+                let expected = u64::MAX >> (64 - byte_width * 8);
+                assert_eq!(reader.read_unsigned_le(0, byte_width), Ok(expected));
+            }
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_widths() {
+            let reader = MockReader::new([0xff; 8]);
+
+            for byte_width in [0, 9] {
+                let error = reader
+                    .read_unsigned_le(0, byte_width)
+                    .expect_err("Byte width should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidRead {
+                        message: "Invalid byte width",
+                    }
+                );
+            }
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0xff; 8]);
+            let error = reader
+                .read_unsigned_le(6, 3)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_unsigned_be {
+        use super::*;
+
+        #[test]
+        fn should_zero_extend_values() {
+            let reader = MockReader::new([0xfe, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_unsigned_be(0, 3), Ok(0xfeffff));
+            assert_eq!(reader.read_unsigned_be(3, 5), Ok(0x8000000000));
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_widths() {
+            let reader = MockReader::new([0xff; 8]);
+
+            for byte_width in [0, 9] {
+                let error = reader
+                    .read_unsigned_be(0, byte_width)
+                    .expect_err("Byte width should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidRead {
+                        message: "Invalid byte width",
+                    }
+                );
+            }
+        }
+    }
+
+    mod read_struct_vec_le {
+        use super::*;
+
+        #[test]
+        fn should_return_values_and_read_bytes() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_struct_vec_le::<u16>(2, 3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, (vec![0x4433, 0xbbaa, 0xddcc], 6));
+        }
+
+        #[test]
+        fn should_return_an_empty_vector_for_a_count_of_0() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_struct_vec_le::<u32>(8, 0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, (vec![], 0));
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_struct_vec_le::<u32>(2, 2)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_the_count_is_larger_than_the_data() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_struct_vec_le::<u32>(0, usize::MAX)
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[derive(Debug)]
+        struct Oversized;
+
+        impl EndianRead for Oversized {
+            fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                Ok(ReadOutput::new(Oversized, usize::MAX))
+            }
+
+            fn try_read_be(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[test]
+        fn should_return_error_with_the_value_size_if_the_offset_overflows() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_struct_vec_le::<Oversized>(1, 2)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: usize::MAX,
+                    offset: 1,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_struct_vec_be {
+        use super::*;
+
+        #[test]
+        fn should_return_values_and_read_bytes() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_struct_vec_be::<u32>(0, 2)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, (vec![0x11223344, 0xaabbccdd], 8));
+        }
+    }
+
+    mod read_unorm8 {
+        use super::*;
+
+        #[test]
+        fn should_return_a_normalized_value() {
+            let reader = MockReader::new([0x00, 0xff, 0, 0, 0, 0, 0, 0]);
+            assert_eq!(reader.read_unorm8(0), Ok(0.0));
+            assert_eq!(reader.read_unorm8(1), Ok(1.0));
+        }
+    }
+
+    mod read_snorm8 {
+        use super::*;
+
+        #[test]
+        fn should_return_a_normalized_value() {
+            let reader = MockReader::new([0x81, 0x7f, 0, 0, 0, 0, 0, 0]);
+            assert_eq!(reader.read_snorm8(0), Ok(-1.0));
+            assert_eq!(reader.read_snorm8(1), Ok(1.0));
+        }
+    }
+
+    mod read_unorm16 {
+        use super::*;
+
+        #[test]
+        fn should_return_a_normalized_value() {
+            let reader = MockReader::new([0xff, 0xff, 0x00, 0x00, 0, 0, 0, 0]);
+            assert_eq!(reader.read_unorm16_le(0), Ok(1.0));
+            assert_eq!(reader.read_unorm16_be(2), Ok(0.0));
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .read_unorm16_le(7)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_snorm16 {
+        use super::*;
+
+        #[test]
+        fn should_return_a_normalized_value() {
+            let reader = MockReader::new([0x01, 0x80, 0x7f, 0xff, 0, 0, 0, 0]);
+            assert_eq!(reader.read_snorm16_le(0), Ok(-1.0));
+            assert_eq!(reader.read_snorm16_be(2), Ok(1.0));
+        }
+    }
+
+    mod read_records_le {
+        use super::*;
+
+        #[test]
+        fn should_read_records_until_the_end_of_the_data() {
+            let reader = MockReader::new([0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+            let (records, count) = reader
+                .read_records_le::<8, u16>(2)
+                .expect("Read should have been successful.");
+
+            assert_eq!(count, 3);
+            assert_eq!(records[..3], [Some(0x2211), Some(0x4433), Some(0x6655)]);
+            assert!(records[3..].iter().all(Option::is_none));
+        }
+
+        #[test]
+        fn should_stop_at_capacity() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+            let result = reader.read_records_le::<2, u16>(0);
+
+            assert_eq!(result, Ok(([Some(0x2211), Some(0x4433)], 2)));
+        }
+
+        #[test]
+        fn should_return_no_records_at_the_end_of_the_data() {
+            let reader = MockReader::new([0; 8]);
+            let result = reader.read_records_le::<2, u16>(8);
+
+            assert_eq!(result, Ok(([None, None], 0)));
+        }
+
+        #[test]
+        fn should_return_error_if_a_record_is_partial() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+            let error = reader
+                .read_records_le::<4, u32>(2)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_records_be {
+        use super::*;
+
+        #[test]
+        fn should_read_records_until_the_end_of_the_data() {
+            let reader = MockReader::new([0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+            let (records, count) = reader
+                .read_records_be::<4, u16>(2)
+                .expect("Read should have been successful.");
+
+            assert_eq!(count, 3);
+            assert_eq!(records, [Some(0x1122), Some(0x3344), Some(0x5566), None]);
+        }
+    }
+
+    mod read_bit_array {
+        use super::*;
+        use crate::BitReader;
+
+        #[derive(Debug, PartialEq)]
+        struct Sample {
+            kind: u8,
+            flag: bool,
+        }
+
+        impl BitRead for Sample {
+            fn try_read_bits<T: Reader>(reader: &mut BitReader<T>) -> ReaderResult<Self> {
+                let kind = reader.read_bits(3)? as u8;
+                let flag = bool::try_read_bits(reader)?;
+                Ok(Self { kind, flag })
+            }
+        }
+
+        #[test]
+        fn should_read_values_that_start_mid_byte() {
+            let reader = MockReader::new([0xff, 0b1011_0110, 0b0011_0000, 0, 0, 0, 0, 0]);
+            let values = reader
+                .read_bit_array::<3, Sample>(1)
+                .expect("Read should have been successful.");
+
+            assert_eq!(
+                values,
+                [
+                    Sample {
+                        kind: 0b101,
+                        flag: true,
+                    },
+                    Sample {
+                        kind: 0b011,
+                        flag: false,
+                    },
+                    Sample {
+                        kind: 0b001,
+                        flag: true,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .read_bit_array::<3, Sample>(7)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_byte_vec {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_byte_vec(4, 3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_byte_vec(6, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod default_read_byte_vec {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             let value = reader.default_read_byte_vec(4, 3);
             assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
@@ -709,4 +2573,108 @@ mod test {
             assert_eq!(value, vec![0, 0, 0, 0]);
         }
     }
+
+    mod dyn_reader {
+        use super::*;
+
+        #[test]
+        fn should_read_from_trait_objects() {
+            let array = [0x11, 0x22, 0x33, 0x44];
+            let vector = vec![0xaa, 0xbb, 0xcc, 0xdd];
+            let mock = MockReader::new([0x01, 0x02, 0x03, 0x04, 0, 0, 0, 0]);
+            let readers: [&dyn DynReader; 3] = [&array, &vector, &mock];
+
+            let values = readers
+                .iter()
+                .map(|reader| reader.read_le::<u16>(2))
+                .collect::<Vec<_>>();
+
+            assert_eq!(values, [Ok(0x4433), Ok(0xddcc), Ok(0x0403)]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader: &dyn DynReader = &[0x11, 0x22, 0x33, 0x44];
+            let error = reader
+                .read_le::<u32>(2)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 2,
+                    data_len: 4,
+                }
+            );
+        }
+    }
+
+    mod chunks {
+        use super::*;
+
+        #[test]
+        fn should_return_a_shorter_last_chunk() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let mut chunks = reader.chunks(3);
+
+            assert_eq!(chunks.next(), Some([1, 2, 3].as_slice()));
+            assert_eq!(chunks.next(), Some([4, 5, 6].as_slice()));
+            assert_eq!(chunks.next(), Some([7, 8].as_slice()));
+            assert_eq!(chunks.next(), None);
+        }
+    }
+
+    mod sub_reader {
+        use super::*;
+
+        #[test]
+        fn should_map_offset_zero_to_base() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let sub_reader = reader
+                .sub_reader(2, 4)
+                .expect("Window should have been valid");
+
+            assert_eq!(sub_reader.get_base(), 2);
+            assert_eq!(sub_reader.read_le::<u8>(0), Ok(3));
+            assert_eq!(sub_reader.read_le::<u16>(2), Ok(0x0605));
+        }
+
+        #[test]
+        fn should_return_error_with_window_length_if_reading_past_window() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let sub_reader = reader
+                .sub_reader(2, 4)
+                .expect("Window should have been valid");
+            let error = sub_reader
+                .read_le::<u32>(2)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 2,
+                    data_len: 4,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_window_exceeds_parent() {
+            let reader = MockReader::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = reader
+                .sub_reader(6, 4)
+                .expect_err("Window should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
 }
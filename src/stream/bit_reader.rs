@@ -0,0 +1,346 @@
+use crate::{padding_to_align, Error, Reader, ReaderResult};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The order bits are read from within each byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Reads from the most significant bit of each byte first.
+    MsbFirst,
+    /// Reads from the least significant bit of each byte first.
+    LsbFirst,
+}
+
+/// Reads packed fields that are smaller than a byte by tracking a bit index.
+///
+/// The offset, size, and data length of an [Error::InvalidSize] are in bits.
+pub struct BitReader<T: Reader> {
+    raw: T,
+    bit_index: usize,
+    bit_order: BitOrder,
+}
+
+impl<T: Reader> BitReader<T> {
+    /// Creates a reader that reads the most significant bit of each byte first.
+    #[inline(always)]
+    pub fn new(raw: T) -> Self {
+        Self::with_bit_order(raw, BitOrder::MsbFirst)
+    }
+
+    #[inline(always)]
+    pub fn with_bit_order(raw: T, bit_order: BitOrder) -> Self {
+        Self {
+            raw,
+            bit_index: 0,
+            bit_order,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_bit_index(&self) -> usize {
+        self.bit_index
+    }
+
+    #[inline(always)]
+    pub fn set_bit_index(&mut self, bit_index: usize) {
+        self.bit_index = bit_index;
+    }
+
+    /// Reads up to 64 bits and advances past them.
+    /// The first bit read is the most significant bit of the result for [BitOrder::MsbFirst],
+    /// and the least significant bit of the result for [BitOrder::LsbFirst].
+    ///
+    /// The index is unchanged if the read fails.
+    pub fn read_bits(&mut self, count: u8) -> ReaderResult<u64> {
+        if count > 64 {
+            return Err(Error::InvalidRead {
+                message: "Cannot read more than 64 bits",
+            });
+        }
+
+        let data = self.raw.get_slice();
+        let count = usize::from(count);
+        let data_bits = data.len().checked_mul(8).ok_or(Error::InvalidRead {
+            message: "Data is too large to address in bits",
+        })?;
+        let end = self
+            .bit_index
+            .checked_add(count)
+            .filter(|end| *end <= data_bits)
+            .ok_or(Error::InvalidSize {
+                wanted_size: count,
+                offset: self.bit_index,
+                data_len: data_bits,
+            })?;
+
+        let mut value = 0u64;
+        for index in 0..count {
+            let bit_index = self.bit_index + index;
+            let byte = data[bit_index / 8];
+            let bit = match self.bit_order {
+                BitOrder::MsbFirst => (byte >> (7 - bit_index % 8)) & 1,
+                BitOrder::LsbFirst => (byte >> (bit_index % 8)) & 1,
+            };
+
+            value = match self.bit_order {
+                BitOrder::MsbFirst => (value << 1) | u64::from(bit),
+                BitOrder::LsbFirst => value | (u64::from(bit) << index),
+            };
+        }
+
+        self.bit_index = end;
+        Ok(value)
+    }
+
+    /// Reads a field for each of the bit widths, such as the fields of a packed register,
+    /// using [BitReader::read_bits] for each field.
+    ///
+    /// The index is unchanged if any of the reads fail.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub fn read_bitfields(&mut self, widths: &[u8]) -> ReaderResult<Vec<u64>> {
+        let bit_index = self.bit_index;
+        let fields = widths
+            .iter()
+            .map(|width| self.read_bits(*width))
+            .collect::<ReaderResult<Vec<u64>>>();
+
+        if fields.is_err() {
+            self.bit_index = bit_index;
+        }
+
+        fields
+    }
+
+    /// Discards any bits remaining in a partially read byte.
+    #[inline(always)]
+    pub fn align_to_byte(&mut self) {
+        self.bit_index = self
+            .bit_index
+            .saturating_add(padding_to_align(self.bit_index, 8));
+    }
+
+    /// Returns the index of the byte the next bit will be read from.
+    #[inline(always)]
+    pub fn get_byte_index(&self) -> usize {
+        self.bit_index / 8
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.raw
+    }
+}
+
+/// A type that can be read starting at any bit of a [BitReader],
+/// such as a record in an array of bit-packed records.
+pub trait BitRead: Sized {
+    /// Reads the value and advances the reader past its bits.
+    fn try_read_bits<T: Reader>(reader: &mut BitReader<T>) -> ReaderResult<Self>;
+}
+
+/// Reads a single bit.
+impl BitRead for bool {
+    #[inline(always)]
+    fn try_read_bits<T: Reader>(reader: &mut BitReader<T>) -> ReaderResult<Self> {
+        Ok(reader.read_bits(1)? != 0)
+    }
+}
+
+macro_rules! impl_bit_read {
+    ($($i:ty),*) => {
+        $(
+            /// Reads every bit of the integer.
+            impl BitRead for $i {
+                #[inline(always)]
+                fn try_read_bits<T: Reader>(reader: &mut BitReader<T>) -> ReaderResult<Self> {
+                    Ok(reader.read_bits(<$i>::BITS as u8)? as $i)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_read!(u8, u16, u32, u64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod read_bits {
+        use super::*;
+
+        #[test]
+        fn should_read_packed_fields_msb_first() {
+            let data = [0b1010_1101];
+            let mut reader = BitReader::new(data.as_slice());
+
+            assert_eq!(reader.read_bits(3), Ok(0b101));
+            assert_eq!(reader.read_bits(5), Ok(0b01101));
+            assert_eq!(reader.get_bit_index(), 8);
+        }
+
+        #[test]
+        fn should_read_packed_fields_lsb_first() {
+            let data = [0b1010_1101];
+            let mut reader = BitReader::with_bit_order(data.as_slice(), BitOrder::LsbFirst);
+
+            assert_eq!(reader.read_bits(3), Ok(0b101));
+            assert_eq!(reader.read_bits(5), Ok(0b10101));
+        }
+
+        #[test]
+        fn should_read_across_bytes() {
+            let data = [0x0a, 0xbc];
+            let mut reader = BitReader::new(data.as_slice());
+
+            assert_eq!(reader.read_bits(4), Ok(0x0));
+            assert_eq!(reader.read_bits(12), Ok(0xabc));
+        }
+
+        #[test]
+        fn should_read_64_bits() {
+            let data = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+            let mut reader = BitReader::new(data.as_slice());
+
+            assert_eq!(reader.read_bits(64), Ok(0x0123456789abcdef));
+        }
+
+        #[test]
+        fn should_return_error_if_reading_past_the_end() {
+            let data = [0xff];
+            let mut reader = BitReader::new(data.as_slice());
+            reader.read_bits(6).unwrap();
+
+            assert_eq!(
+                reader.read_bits(3),
+                Err(Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(reader.get_bit_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_if_the_bit_index_would_overflow() {
+            let data = [0xff];
+            let mut reader = BitReader::new(data.as_slice());
+            reader.set_bit_index(usize::MAX);
+
+            assert_eq!(
+                reader.read_bits(3),
+                Err(Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: usize::MAX,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(reader.get_bit_index(), usize::MAX);
+        }
+
+        #[test]
+        fn should_return_error_if_count_is_too_large() {
+            let data = [0; 16];
+            let mut reader = BitReader::new(data.as_slice());
+
+            assert_eq!(
+                reader.read_bits(65),
+                Err(Error::InvalidRead {
+                    message: "Cannot read more than 64 bits",
+                })
+            );
+        }
+    }
+
+    mod read_bitfields {
+        use super::*;
+
+        #[test]
+        fn should_read_each_field() {
+            let data = [0b1010_1101, 0x12];
+            let mut reader = BitReader::new(data.as_slice());
+
+            assert_eq!(
+                reader.read_bitfields(&[3, 5, 8]),
+                Ok(alloc::vec![0b101, 0b01101, 0x12])
+            );
+            assert_eq!(reader.get_bit_index(), 16);
+        }
+
+        #[test]
+        fn should_return_error_if_the_widths_are_too_large() {
+            let data = [0b1010_1101, 0x12];
+            let mut reader = BitReader::new(data.as_slice());
+            reader.read_bits(1).unwrap();
+
+            assert_eq!(
+                reader.read_bitfields(&[3, 5, 8]),
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 9,
+                    data_len: 16,
+                })
+            );
+            assert_eq!(reader.get_bit_index(), 1);
+        }
+    }
+
+    mod bit_read {
+        use super::*;
+
+        #[test]
+        fn should_read_bools_and_integers_across_bytes() {
+            let data = [0b1000_0000, 0b1111_1111, 0b1000_0000];
+            let mut reader = BitReader::new(data.as_slice());
+
+            assert_eq!(bool::try_read_bits(&mut reader), Ok(true));
+            assert_eq!(u16::try_read_bits(&mut reader), Ok(0x01ff));
+            assert_eq!(bool::try_read_bits(&mut reader), Ok(false));
+            assert_eq!(reader.get_bit_index(), 18);
+        }
+
+        #[test]
+        fn should_return_error_if_reading_past_the_end() {
+            let data = [0xff];
+            let mut reader = BitReader::new(data.as_slice());
+            reader.read_bits(1).unwrap();
+
+            assert_eq!(
+                u8::try_read_bits(&mut reader),
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 1,
+                    data_len: 8,
+                })
+            );
+        }
+    }
+
+    mod align_to_byte {
+        use super::*;
+
+        #[test]
+        fn should_discard_partial_bits() {
+            let data = [0xff, 0x12];
+            let mut reader = BitReader::new(data.as_slice());
+            reader.read_bits(3).unwrap();
+            reader.align_to_byte();
+
+            assert_eq!(reader.get_byte_index(), 1);
+            assert_eq!(reader.read_bits(8), Ok(0x12));
+        }
+
+        #[test]
+        fn should_not_move_if_already_aligned() {
+            let data = [0xff, 0x12];
+            let mut reader = BitReader::new(data.as_slice());
+            reader.read_bits(8).unwrap();
+            reader.align_to_byte();
+
+            assert_eq!(reader.get_bit_index(), 8);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{Cursor, Reader, StreamReader};
+
+/// A running checksum that bytes can be fed into as they're read.
+pub trait Checksum {
+    fn update(&mut self, bytes: &[u8]);
+    fn finalize(&self) -> u32;
+}
+
+/// The CRC-32 checksum used by zlib, PNG, and zip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc32 {
+    crc: u32,
+}
+
+impl Default for Crc32 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self { crc: 0xffffffff }
+    }
+}
+
+impl Checksum for Crc32 {
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.crc ^= u32::from(*byte);
+            for _ in 0..8 {
+                let mask = (self.crc & 1).wrapping_neg();
+                self.crc = (self.crc >> 1) ^ (0xedb88320 & mask);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u32 {
+        !self.crc
+    }
+}
+
+/// A CRC-16 checksum, which is CRC-16/CCITT-FALSE by default.
+///
+/// CRC-16/CCITT-FALSE uses the CCITT polynomial `0x1021`, an initial value of `0xffff`,
+/// no reflection, and no final XOR. Other non-reflected CRC-16 variants,
+/// such as CRC-16/XMODEM, can be used with [Crc16::new].
+/// The checksum is in the low 16 bits of [Checksum::finalize].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc16 {
+    crc: u16,
+    polynomial: u16,
+}
+
+impl Crc16 {
+    #[inline(always)]
+    pub fn new(polynomial: u16, initial: u16) -> Self {
+        Self {
+            crc: initial,
+            polynomial,
+        }
+    }
+}
+
+impl Default for Crc16 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0x1021, 0xffff)
+    }
+}
+
+impl Checksum for Crc16 {
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.crc ^= u16::from(*byte) << 8;
+            for _ in 0..8 {
+                let mask = (self.crc >> 15).wrapping_neg();
+                self.crc = (self.crc << 1) ^ (self.polynomial & mask);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u32 {
+        u32::from(self.crc)
+    }
+}
+
+/// A wrapping sum of every byte.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sum32 {
+    sum: u32,
+}
+
+impl Checksum for Sum32 {
+    #[inline(always)]
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.sum = self.sum.wrapping_add(u32::from(*byte));
+        }
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u32 {
+        self.sum
+    }
+}
+
+/// Wraps a stream and feeds every byte it moves forward past into a checksum,
+/// so a region can be verified while it's parsed instead of in a separate pass.
+///
+/// Moving the index backwards does not remove bytes from the checksum,
+/// and bytes are fed again if they're moved past a second time.
+pub struct ChecksummingReader<T: StreamReader, C: Checksum = Crc32> {
+    stream: T,
+    checksum: C,
+}
+
+impl<T: StreamReader, C: Checksum + Default> ChecksummingReader<T, C> {
+    #[inline(always)]
+    pub fn new(stream: T) -> Self {
+        Self {
+            stream,
+            checksum: C::default(),
+        }
+    }
+}
+
+impl<T: StreamReader, C: Checksum> ChecksummingReader<T, C> {
+    /// Returns the checksum of every byte read so far.
+    #[inline(always)]
+    pub fn finalize_checksum(&self) -> u32 {
+        self.checksum.finalize()
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.stream
+    }
+}
+
+impl<T: StreamReader, C: Checksum> Reader for ChecksummingReader<T, C> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        self.stream.get_slice()
+    }
+}
+
+impl<T: StreamReader, C: Checksum> Cursor for ChecksummingReader<T, C> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.stream.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        let data = self.stream.get_slice();
+        let start = self.stream.get_index().min(data.len());
+        let end = index.min(data.len());
+
+        if start < end {
+            self.checksum.update(&data[start..end]);
+        }
+
+        self.stream.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::StreamContainer;
+
+    mod crc32 {
+        use super::*;
+
+        #[test]
+        fn should_match_the_check_value() {
+            let mut crc = Crc32::default();
+            crc.update(b"123456789");
+            assert_eq!(crc.finalize(), 0xcbf43926);
+        }
+    }
+
+    mod crc16 {
+        use super::*;
+
+        #[test]
+        fn should_match_the_ccitt_false_check_value() {
+            let mut crc = Crc16::default();
+            crc.update(b"123456789");
+            assert_eq!(crc.finalize(), 0x29b1);
+        }
+
+        #[test]
+        fn should_match_the_xmodem_check_value() {
+            let mut crc = Crc16::new(0x1021, 0);
+            crc.update(b"123456789");
+            assert_eq!(crc.finalize(), 0x31c3);
+        }
+    }
+
+    mod checksumming_reader {
+        use super::*;
+
+        #[test]
+        fn should_checksum_read_bytes() {
+            let data = b"123456789\xaa\xbb";
+            let mut reader: ChecksummingReader<_> =
+                ChecksummingReader::new(StreamContainer::new(data.as_slice()));
+            reader.read_stream_le::<u32>().unwrap();
+            reader.read_stream_be::<u32>().unwrap();
+            reader.read_stream_le::<u8>().unwrap();
+
+            assert_eq!(reader.get_index(), 9);
+            assert_eq!(reader.finalize_checksum(), 0xcbf43926);
+        }
+
+        #[test]
+        fn should_not_checksum_failed_reads() {
+            let data = [0x01, 0x02, 0x03];
+            let mut reader: ChecksummingReader<_, Sum32> =
+                ChecksummingReader::new(StreamContainer::new(data.as_slice()));
+            reader.read_stream_le::<u16>().unwrap();
+            reader.read_stream_le::<u16>().unwrap_err();
+
+            assert_eq!(reader.finalize_checksum(), 3);
+        }
+
+        #[test]
+        fn should_not_checksum_when_moving_backwards() {
+            let data = [0x01, 0x02, 0x03];
+            let mut reader: ChecksummingReader<_, Sum32> =
+                ChecksummingReader::new(StreamContainer::new(data.as_slice()));
+            reader.read_stream_le::<u16>().unwrap();
+            reader.set_index(1);
+
+            assert_eq!(reader.finalize_checksum(), 3);
+        }
+
+        #[test]
+        fn should_return_the_inner_stream() {
+            let data = [0x01, 0x02, 0x03];
+            let mut reader: ChecksummingReader<_, Sum32> =
+                ChecksummingReader::new(StreamContainer::new(data.as_slice()));
+            reader.read_stream_le::<u16>().unwrap();
+
+            assert_eq!(reader.into_inner().get_index(), 2);
+        }
+    }
+}
@@ -12,18 +12,59 @@ use alloc::vec::Vec;
 pub struct StreamContainer<T: Reader> {
     raw: T,
     cursor: usize,
+    high_water_mark: usize,
 }
 
 impl<T: Reader> StreamContainer<T> {
     #[inline(always)]
     pub fn new(raw: T) -> Self {
-        Self { raw, cursor: 0 }
+        Self {
+            raw,
+            cursor: 0,
+            high_water_mark: 0,
+        }
+    }
+
+    /// Returns the furthest index the container has reached,
+    /// even if the index was later moved backwards, such as to patch a header.
+    #[inline(always)]
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+
+    /// Replaces the wrapped value and resets the index and high water mark to 0,
+    /// so the container can be reused for new data.
+    #[inline(always)]
+    pub fn reset(&mut self, raw: T) {
+        self.raw = raw;
+        self.cursor = 0;
+        self.high_water_mark = 0;
     }
 
     #[inline(always)]
     pub fn into_raw(self) -> T {
         self.raw
     }
+
+    /// Same as [StreamContainer::into_raw].
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.into_raw()
+    }
+}
+
+impl<T: Reader> AsRef<T> for StreamContainer<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        &self.raw
+    }
+}
+
+impl<T: Reader> AsMut<T> for StreamContainer<T> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.raw
+    }
 }
 
 impl<T: Reader> Reader for StreamContainer<T> {
@@ -64,6 +105,7 @@ impl<T: Reader> Cursor for StreamContainer<T> {
     #[inline(always)]
     fn set_index(&mut self, index: usize) {
         self.cursor = index;
+        self.high_water_mark = self.high_water_mark.max(index);
     }
 }
 
@@ -88,6 +130,23 @@ impl<const SIZE: usize> From<StreamContainer<[u8; SIZE]>> for [u8; SIZE] {
     }
 }
 
+/// A [StreamContainer] over an owned vector, which grows as values are written past its end.
+/// Any gap left by seeking forward before a write is zero filled.
+#[cfg(feature = "alloc")]
+pub type GrowableStreamWriter = StreamContainer<Vec<u8>>;
+
+#[cfg(feature = "alloc")]
+impl StreamContainer<Vec<u8>> {
+    /// Consumes the container and returns the vector truncated to the current index,
+    /// which is exactly the bytes written when building a buffer with a stream.
+    #[inline(always)]
+    pub fn into_vec(self) -> Vec<u8> {
+        let mut raw = self.raw;
+        raw.truncate(self.cursor);
+        raw
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl From<StreamContainer<Vec<u8>>> for Vec<u8> {
     #[inline(always)]
@@ -163,4 +222,106 @@ mod test {
             [0xaa, 0xbb, 0xcc, 0xdd, 0xaa, 0xbb, 0xcc, 0xdd]
         );
     }
+
+    #[test]
+    fn should_grow_past_the_initial_capacity() {
+        let mut stream = GrowableStreamWriter::new(Vec::with_capacity(2));
+        stream.write_stream_le(&0xaabbccddu32).unwrap();
+        stream.write_stream_be(&0x1122u16).unwrap();
+
+        assert_eq!(stream.get_index(), 6);
+        assert_eq!(stream.into_raw(), [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22]);
+    }
+
+    #[test]
+    fn should_zero_fill_a_forward_seek() {
+        let mut stream = GrowableStreamWriter::new(vec![0xff]);
+        stream.set_index(3);
+        stream.write_stream_le(&0xaabbu16).unwrap();
+
+        assert_eq!(stream.get_index(), 5);
+        assert_eq!(stream.into_raw(), [0xff, 0x00, 0x00, 0xbb, 0xaa]);
+    }
+
+    #[test]
+    fn should_keep_the_high_water_mark_after_seeking_backwards() {
+        let mut stream = StreamContainer::new(vec![]);
+        stream.write_stream_le(&0u32).unwrap();
+        stream.write_stream_le(&0xaabbu16).unwrap();
+        stream.set_index(0);
+        stream.write_stream_le(&6u32).unwrap();
+
+        assert_eq!(stream.get_index(), 4);
+        assert_eq!(stream.high_water_mark(), 6);
+    }
+
+    #[test]
+    fn should_track_reads_in_the_high_water_mark() {
+        let data = [0xaa, 0xbb, 0xcc, 0xdd];
+        let mut stream = StreamContainer::new(data.as_slice());
+        assert_eq!(stream.high_water_mark(), 0);
+
+        stream.read_stream_le::<u16>().unwrap();
+        stream.rewind();
+        assert_eq!(stream.high_water_mark(), 2);
+    }
+
+    #[test]
+    fn should_reset_over_new_data() {
+        let first = [0x11, 0x22, 0x33, 0x44];
+        let second = [0xaa, 0xbb];
+        let mut stream = StreamContainer::new(first.as_slice());
+        assert_eq!(stream.read_stream_le::<u32>(), Ok(0x44332211));
+
+        stream.reset(second.as_slice());
+        assert_eq!(stream.get_index(), 0);
+        assert_eq!(stream.high_water_mark(), 0);
+        assert_eq!(stream.read_stream_be::<u16>(), Ok(0xaabb));
+
+        stream.reset(first.as_slice());
+        assert_eq!(stream.get_index(), 0);
+        assert_eq!(stream.read_stream_be::<u16>(), Ok(0x1122));
+    }
+
+    #[test]
+    fn should_return_written_bytes_with_into_vec() {
+        let mut stream = StreamContainer::new(vec![]);
+        stream.write_stream_le(&0xaabbccddu32).unwrap();
+        stream.write_stream_be(&0x1122u16).unwrap();
+        assert_eq!(stream.into_vec(), [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22]);
+    }
+
+    #[test]
+    fn should_truncate_to_the_index_with_into_vec() {
+        let mut stream = StreamContainer::new(vec![0; 8]);
+        stream.write_stream_le(&0xaabbccddu32).unwrap();
+        assert_eq!(stream.into_vec(), [0xdd, 0xcc, 0xbb, 0xaa]);
+    }
+
+    #[test]
+    fn should_return_the_exact_bytes_with_into_inner() {
+        let mut stream = StreamContainer::new(vec![]);
+        stream.write_stream_le(&0xaabbccddu32).unwrap();
+        stream.write_stream_be(&0x1122u16).unwrap();
+        assert_eq!(stream.into_inner(), [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22]);
+    }
+
+    #[test]
+    fn should_return_a_mut_slice_with_into_inner() {
+        let mut data = [0; 4];
+        let mut stream = StreamContainer::new(data.as_mut_slice());
+        stream.write_stream_be(&0xaabbu16).unwrap();
+        let raw = stream.into_inner();
+        raw[3] = 0xcc;
+        assert_eq!(data, [0xaa, 0xbb, 0x00, 0xcc]);
+    }
+
+    #[test]
+    fn should_borrow_the_wrapped_value() {
+        let mut stream = StreamContainer::new(vec![0xaa, 0xbb]);
+        assert_eq!(stream.as_ref(), &[0xaa, 0xbb]);
+
+        stream.as_mut().push(0xcc);
+        assert_eq!(stream.read_stream_le::<[u8; 3]>(), Ok([0xaa, 0xbb, 0xcc]));
+    }
 }
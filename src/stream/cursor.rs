@@ -1,5 +1,15 @@
 use core::mem::size_of;
 
+/// Returns the number of bytes needed to move the offset to the next multiple of the alignment.
+/// Already aligned offsets and an alignment of 0 need no padding.
+#[inline(always)]
+pub fn padding_to_align(offset: usize, alignment: usize) -> usize {
+    match alignment {
+        0 => 0,
+        alignment => (alignment - offset % alignment) % alignment,
+    }
+}
+
 /// An interface for working with cursors by getting and setting an index.
 pub trait Cursor {
     fn get_index(&self) -> usize;
@@ -26,6 +36,18 @@ pub trait Cursor {
         let size = size_of::<T>();
         self.swap_incremented_index(size)
     }
+
+    /// Increments the index to the next multiple of the alignment.
+    /// Returns the number of bytes skipped.
+    ///
+    /// An alignment of 0 is a no-op and other alignments aren't validated.
+    /// Use [crate::StreamReader::try_align_to] to validate the alignment and bounds.
+    #[inline(always)]
+    fn align_to(&mut self, alignment: usize) -> usize {
+        let padding = padding_to_align(self.get_index(), alignment);
+        self.increment_by(padding);
+        padding
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +105,56 @@ mod test {
         assert_eq!(previous_index, 3);
         assert_eq!(current_index, 7);
     }
+
+    mod padding_to_align {
+        use super::*;
+
+        #[test]
+        fn should_return_padding_to_the_next_boundary() {
+            assert_eq!(padding_to_align(5, 4), 3);
+            assert_eq!(padding_to_align(1, 8), 7);
+        }
+
+        #[test]
+        fn should_return_0_if_already_aligned() {
+            assert_eq!(padding_to_align(0, 4), 0);
+            assert_eq!(padding_to_align(8, 4), 0);
+        }
+
+        #[test]
+        fn should_return_0_for_an_alignment_of_0() {
+            assert_eq!(padding_to_align(5, 0), 0);
+        }
+    }
+
+    mod align_to {
+        use super::*;
+
+        #[test]
+        fn should_increment_to_the_next_boundary() {
+            let mut cursor = MockCursor::new(5);
+            let padding = cursor.align_to(4);
+
+            assert_eq!(padding, 3);
+            assert_eq!(cursor.get_index(), 8);
+        }
+
+        #[test]
+        fn should_increment_from_3_to_4() {
+            let mut cursor = MockCursor::new(3);
+            let padding = cursor.align_to(4);
+
+            assert_eq!(padding, 1);
+            assert_eq!(cursor.get_index(), 4);
+        }
+
+        #[test]
+        fn should_not_move_if_already_aligned() {
+            let mut cursor = MockCursor::new(8);
+            let padding = cursor.align_to(4);
+
+            assert_eq!(padding, 0);
+            assert_eq!(cursor.get_index(), 8);
+        }
+    }
 }
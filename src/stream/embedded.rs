@@ -0,0 +1,105 @@
+use super::{container::StreamContainer, reader::StreamReader};
+use crate::{Cursor, Error, Reader, Writer};
+use embedded_io::{ErrorKind, ErrorType, Read, Write};
+
+impl embedded_io::Error for Error {
+    #[inline(always)]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidSize { .. } => ErrorKind::Other,
+            Error::InvalidAlignment { .. }
+            | Error::InvalidUtf8 { .. }
+            | Error::InvalidRead { .. } => ErrorKind::InvalidData,
+            Error::InvalidWrite { .. } => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+impl<T: Reader> ErrorType for StreamContainer<T> {
+    type Error = Error;
+}
+
+/// Reads from the current index and advances past the bytes read.
+impl<T: Reader> Read for StreamContainer<T> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let remaining = self.remaining_slice();
+        let size = remaining.len().min(buf.len());
+        buf[..size].copy_from_slice(&remaining[..size]);
+        self.increment_by(size);
+        Ok(size)
+    }
+}
+
+/// Writes all of the bytes to the current index and advances past them.
+/// Nothing is written if the bytes don't fit.
+impl<T: Reader + Writer> Write for StreamContainer<T> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let index = self.get_index();
+        let bytes_written = self.write_bytes(index, buf)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod read {
+        use super::*;
+
+        #[test]
+        fn should_read_from_the_index() {
+            let data = [0x11, 0x22, 0x33, 0x44, 0x55];
+            let mut stream = StreamContainer::new(data.as_slice());
+            stream.set_index(1);
+            let mut buf = [0; 3];
+
+            assert_eq!(Read::read(&mut stream, &mut buf), Ok(3));
+            assert_eq!(buf, [0x22, 0x33, 0x44]);
+            assert_eq!(stream.get_index(), 4);
+        }
+
+        #[test]
+        fn should_read_the_remaining_bytes_at_the_end() {
+            let data = [0x11, 0x22, 0x33];
+            let mut stream = StreamContainer::new(data.as_slice());
+            let mut buf = [0; 4];
+
+            assert_eq!(Read::read(&mut stream, &mut buf), Ok(3));
+            assert_eq!(Read::read(&mut stream, &mut buf), Ok(0));
+            assert_eq!(buf, [0x11, 0x22, 0x33, 0x00]);
+        }
+    }
+
+    mod write {
+        use super::*;
+
+        #[test]
+        fn should_write_to_the_index() {
+            let mut stream = StreamContainer::new([0; 4]);
+            stream.set_index(1);
+
+            assert_eq!(Write::write(&mut stream, &[0xaa, 0xbb]), Ok(2));
+            assert_eq!(stream.get_index(), 3);
+            assert_eq!(stream.into_raw(), [0x00, 0xaa, 0xbb, 0x00]);
+        }
+
+        #[test]
+        fn should_return_error_if_bytes_do_not_fit() {
+            let mut stream = StreamContainer::new([0; 4]);
+            stream.set_index(3);
+            let error = Write::write(&mut stream, &[0xaa, 0xbb]).unwrap_err();
+
+            assert_eq!(embedded_io::Error::kind(&error), ErrorKind::Other);
+            assert_eq!(stream.get_index(), 3);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::cursor::Cursor;
+use core::ops::{Deref, DerefMut};
+
+/// Restores a stream's index when dropped, unless [CursorGuard::commit] is called.
+///
+/// The guard dereferences to the stream, so reads can continue through it.
+pub struct CursorGuard<'a, T: Cursor + ?Sized> {
+    stream: &'a mut T,
+    index: usize,
+    committed: bool,
+}
+
+impl<'a, T: Cursor + ?Sized> CursorGuard<'a, T> {
+    #[inline(always)]
+    pub fn new(stream: &'a mut T) -> Self {
+        let index = stream.get_index();
+        Self {
+            stream,
+            index,
+            committed: false,
+        }
+    }
+
+    /// Returns the index that will be restored.
+    #[inline(always)]
+    pub fn get_saved_index(&self) -> usize {
+        self.index
+    }
+
+    /// Keeps the stream's current index instead of restoring it.
+    #[inline(always)]
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<'a, T: Cursor + ?Sized> Deref for CursorGuard<'a, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.stream
+    }
+}
+
+impl<'a, T: Cursor + ?Sized> DerefMut for CursorGuard<'a, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stream
+    }
+}
+
+impl<'a, T: Cursor + ?Sized> Drop for CursorGuard<'a, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if !self.committed {
+            self.stream.set_index(self.index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{StreamContainer, StreamReader};
+
+    #[test]
+    fn should_restore_the_index_without_commit() {
+        let mut stream = StreamContainer::new([0x11, 0x22, 0x33, 0x44]);
+        stream.set_index(1);
+
+        {
+            let mut guard = CursorGuard::new(&mut stream);
+            assert_eq!(guard.read_stream_le::<u16>(), Ok(0x3322));
+            assert_eq!(guard.get_index(), 3);
+            assert_eq!(guard.get_saved_index(), 1);
+        }
+
+        assert_eq!(stream.get_index(), 1);
+    }
+
+    #[test]
+    fn should_keep_the_index_after_commit() {
+        let mut stream = StreamContainer::new([0x11, 0x22, 0x33, 0x44]);
+
+        let mut guard = CursorGuard::new(&mut stream);
+        assert_eq!(guard.read_stream_le::<u16>(), Ok(0x2211));
+        guard.commit();
+
+        assert_eq!(stream.get_index(), 2);
+    }
+}
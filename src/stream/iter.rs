@@ -1,17 +1,77 @@
-use crate::{EndianRead, StreamReader};
+use crate::{EndianRead, ReaderResult, StreamReader};
 use core::marker::PhantomData;
+use safe_transmute::TriviallyTransmutable;
+
+#[inline(always)]
+fn remaining_bytes<Stream: StreamReader>(stream: &Stream, end: usize) -> usize {
+    end.saturating_sub(stream.get_index())
+}
+
+/// The size of every item, which counting items and reading them from the back rely on.
+struct FixedSize<Item>(PhantomData<Item>);
+
+impl<Item: EndianRead> FixedSize<Item> {
+    /// Fails to compile if the items don't have a non-zero [EndianRead::FIXED_SIZE],
+    /// so [Iterator::size_hint] is always exact when this is used.
+    const SIZE: usize = match Item::FIXED_SIZE {
+        Some(size) if size > 0 => size,
+        _ => panic!("Items must have a non-zero FIXED_SIZE to be counted or read from the back"),
+    };
+}
+
+/// Returns an exact size hint when the items have a fixed size, using the same size as
+/// [ExactSizeIterator::len].
+#[inline(always)]
+fn size_hint<Item: EndianRead, Stream: StreamReader>(
+    stream: &Stream,
+    end: usize,
+) -> (usize, Option<usize>) {
+    match Item::FIXED_SIZE {
+        Some(size) if size > 0 => {
+            let count = remaining_bytes(stream, end) / size;
+            (count, Some(count))
+        }
+        _ => (0, None),
+    }
+}
+
+/// Returns the end of the last whole item from the stream's index when the items have a fixed size,
+/// so reading from the back lines up with the items read from the front.
+/// Otherwise returns the end of the data.
+#[inline(always)]
+fn aligned_end<Item: EndianRead, Stream: StreamReader>(stream: &Stream) -> usize {
+    let len = stream.get_slice().len();
+
+    match Item::FIXED_SIZE {
+        Some(size) if size > 0 => {
+            let index = stream.get_index().min(len);
+            index + (len - index) / size * size
+        }
+        _ => len,
+    }
+}
 
 /// An iterator for the little endian representation of an [EndianRead] type from a [StreamReader].
+///
+/// The iterator is double ended and has an exact length when the items are [TriviallyTransmutable]
+/// and have a [EndianRead::FIXED_SIZE], since reading from the back relies on every item
+/// being the same size.
+/// Items are read from the back starting at the end of the last whole item,
+/// so any trailing bytes that can't form an item are skipped from both ends,
+/// and the front and back stop when they meet.
 pub struct LeIter<Item: EndianRead, Stream: StreamReader> {
     data: PhantomData<Item>,
     stream: Stream,
+    end: usize,
 }
 
 impl<Item: EndianRead, Stream: StreamReader> LeIter<Item, Stream> {
     pub fn new(stream: Stream) -> Self {
+        let end = aligned_end::<Item, Stream>(&stream);
         Self {
             data: PhantomData,
             stream,
+            end,
         }
     }
 }
@@ -20,21 +80,68 @@ impl<Item: EndianRead, Stream: StreamReader> Iterator for LeIter<Item, Stream> {
     type Item = Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.stream.read_stream_le().ok()
+        let index = self.stream.get_index();
+        let item = self.stream.read_stream_le().ok()?;
+
+        if self.stream.get_index() > self.end {
+            self.stream.set_index(index);
+            return None;
+        }
+
+        Some(item)
+    }
+
+    /// Exact when [EndianRead::FIXED_SIZE] is set, otherwise `(0, None)`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::<Item, Stream>(&self.stream, self.end)
+    }
+}
+
+impl<Item: EndianRead + TriviallyTransmutable, Stream: StreamReader> ExactSizeIterator
+    for LeIter<Item, Stream>
+{
+    fn len(&self) -> usize {
+        remaining_bytes(&self.stream, self.end) / FixedSize::<Item>::SIZE
+    }
+}
+
+impl<Item: EndianRead + TriviallyTransmutable, Stream: StreamReader> DoubleEndedIterator
+    for LeIter<Item, Stream>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let start = self.end.checked_sub(FixedSize::<Item>::SIZE)?;
+
+        if start < self.stream.get_index() {
+            return None;
+        }
+
+        let item = self.stream.read_le(start).ok()?;
+        self.end = start;
+        Some(item)
     }
 }
 
 /// An iterator for the big endian representation of an [EndianRead] type from a [StreamReader].
+///
+/// The iterator is double ended and has an exact length when the items are [TriviallyTransmutable]
+/// and have a [EndianRead::FIXED_SIZE], since reading from the back relies on every item
+/// being the same size.
+/// Items are read from the back starting at the end of the last whole item,
+/// so any trailing bytes that can't form an item are skipped from both ends,
+/// and the front and back stop when they meet.
 pub struct BeIter<Item: EndianRead, Stream: StreamReader> {
     data: PhantomData<Item>,
     stream: Stream,
+    end: usize,
 }
 
 impl<Item: EndianRead, Stream: StreamReader> BeIter<Item, Stream> {
     pub fn new(stream: Stream) -> Self {
+        let end = aligned_end::<Item, Stream>(&stream);
         Self {
             data: PhantomData,
             stream,
+            end,
         }
     }
 }
@@ -43,14 +150,115 @@ impl<Item: EndianRead, Stream: StreamReader> Iterator for BeIter<Item, Stream> {
     type Item = Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.stream.read_stream_be().ok()
+        let index = self.stream.get_index();
+        let item = self.stream.read_stream_be().ok()?;
+
+        if self.stream.get_index() > self.end {
+            self.stream.set_index(index);
+            return None;
+        }
+
+        Some(item)
+    }
+
+    /// Exact when [EndianRead::FIXED_SIZE] is set, otherwise `(0, None)`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::<Item, Stream>(&self.stream, self.end)
+    }
+}
+
+impl<Item: EndianRead + TriviallyTransmutable, Stream: StreamReader> ExactSizeIterator
+    for BeIter<Item, Stream>
+{
+    fn len(&self) -> usize {
+        remaining_bytes(&self.stream, self.end) / FixedSize::<Item>::SIZE
+    }
+}
+
+impl<Item: EndianRead + TriviallyTransmutable, Stream: StreamReader> DoubleEndedIterator
+    for BeIter<Item, Stream>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let start = self.end.checked_sub(FixedSize::<Item>::SIZE)?;
+
+        if start < self.stream.get_index() {
+            return None;
+        }
+
+        let item = self.stream.read_be(start).ok()?;
+        self.end = start;
+        Some(item)
+    }
+}
+
+/// Same as [LeIter], but yields the error of a failed read instead of stopping silently.
+/// Iteration ends cleanly once no bytes are left, and ends after the first error otherwise.
+pub struct LeTryIter<Item: EndianRead, Stream: StreamReader> {
+    data: PhantomData<Item>,
+    stream: Stream,
+    done: bool,
+}
+
+impl<Item: EndianRead, Stream: StreamReader> LeTryIter<Item, Stream> {
+    pub fn new(stream: Stream) -> Self {
+        Self {
+            data: PhantomData,
+            stream,
+            done: false,
+        }
+    }
+}
+
+impl<Item: EndianRead, Stream: StreamReader> Iterator for LeTryIter<Item, Stream> {
+    type Item = ReaderResult<Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.stream.is_empty() {
+            return None;
+        }
+
+        let result = self.stream.read_stream_le();
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+
+/// Same as [BeIter], but yields the error of a failed read instead of stopping silently.
+/// Iteration ends cleanly once no bytes are left, and ends after the first error otherwise.
+pub struct BeTryIter<Item: EndianRead, Stream: StreamReader> {
+    data: PhantomData<Item>,
+    stream: Stream,
+    done: bool,
+}
+
+impl<Item: EndianRead, Stream: StreamReader> BeTryIter<Item, Stream> {
+    pub fn new(stream: Stream) -> Self {
+        Self {
+            data: PhantomData,
+            stream,
+            done: false,
+        }
+    }
+}
+
+impl<Item: EndianRead, Stream: StreamReader> Iterator for BeTryIter<Item, Stream> {
+    type Item = ReaderResult<Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.stream.is_empty() {
+            return None;
+        }
+
+        let result = self.stream.read_stream_be();
+        self.done = result.is_err();
+        Some(result)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::StreamContainer;
+    use crate::{Cursor, StreamContainer};
     use alloc::vec::Vec;
 
     mod le_iter {
@@ -63,6 +271,77 @@ mod test {
             let result: Vec<u32> = LeIter::new(stream).collect();
             assert_eq!(result, [0xddccbbaa, 0x44332211])
         }
+
+        #[test]
+        fn should_have_an_exact_len() {
+            let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+            let stream = StreamContainer::new(bytes);
+            let mut iter = LeIter::<u16, _>::new(stream);
+
+            assert_eq!(iter.len(), 4);
+            assert_eq!(iter.size_hint(), (4, Some(4)));
+
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.len(), 2);
+        }
+
+        #[test]
+        fn should_not_hint_for_variable_sizes() {
+            let bytes: [u8; 8] = [0x01, 0xaa, 0x00, 0x01, 0xbb, 0x00, 0x00, 0x00];
+            let stream = StreamContainer::new(bytes);
+            let iter = LeIter::<Option<u8>, _>::new(stream);
+
+            assert_eq!(iter.size_hint(), (0, None));
+        }
+
+        #[test]
+        fn should_iterate_from_the_back() {
+            let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+            let stream = StreamContainer::new(bytes);
+            let result: Vec<u32> = LeIter::new(stream).rev().collect();
+            assert_eq!(result, [0x44332211, 0xddccbbaa])
+        }
+
+        #[test]
+        fn should_stop_when_the_front_and_back_meet() {
+            let bytes: [u8; 6] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22];
+            let stream = StreamContainer::new(bytes);
+            let mut iter = LeIter::<u16, _>::new(stream);
+
+            assert_eq!(iter.next_back(), Some(0x2211));
+            assert_eq!(iter.next(), Some(0xbbaa));
+            assert_eq!(iter.next(), Some(0xddcc));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn should_not_read_the_front_past_the_back() {
+            let bytes: [u8; 6] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22];
+            let stream = StreamContainer::new(bytes);
+            let mut iter = LeIter::<u16, _>::new(stream);
+            iter.next_back();
+            iter.next_back();
+
+            assert_eq!(iter.next(), Some(0xbbaa));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_skip_trailing_bytes_from_the_back() {
+            let bytes: [u8; 6] = [0xff, 0xaa, 0xbb, 0xcc, 0xdd, 0x11];
+            let mut stream = StreamContainer::new(bytes);
+            stream.set_index(1);
+            let forward: Vec<u16> = LeIter::new(stream).collect();
+
+            let mut stream = StreamContainer::new(bytes);
+            stream.set_index(1);
+            let backward: Vec<u16> = LeIter::new(stream).rev().collect();
+
+            assert_eq!(forward, [0xbbaa, 0xddcc]);
+            assert_eq!(backward, [0xddcc, 0xbbaa]);
+        }
     }
 
     mod be_iter {
@@ -75,5 +354,82 @@ mod test {
             let result: Vec<u32> = BeIter::new(stream).collect();
             assert_eq!(result, [0xaabbccdd, 0x11223344])
         }
+
+        #[test]
+        fn should_iterate_from_the_back() {
+            let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+            let stream = StreamContainer::new(bytes);
+            let mut iter = BeIter::<u32, _>::new(stream);
+
+            assert_eq!(iter.next_back(), Some(0x11223344));
+            assert_eq!(iter.next_back(), Some(0xaabbccdd));
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn should_skip_trailing_bytes_from_the_back() {
+            let bytes: [u8; 7] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33];
+            let stream = StreamContainer::new(bytes);
+            let mut iter = BeIter::<u16, _>::new(stream);
+
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            assert_eq!(iter.next_back(), Some(0x1122));
+            assert_eq!(iter.next(), Some(0xaabb));
+            assert_eq!(iter.next(), Some(0xccdd));
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    mod le_try_iter {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_iterate() {
+            let bytes: [u8; 4] = [0xaa, 0xbb, 0xcc, 0xdd];
+            let stream = StreamContainer::new(bytes);
+            let result: ReaderResult<Vec<u16>> = LeTryIter::new(stream).collect();
+            assert_eq!(result, Ok(alloc::vec![0xbbaa, 0xddcc]))
+        }
+
+        #[test]
+        fn should_yield_an_error_for_a_partial_item() {
+            let bytes: [u8; 5] = [0xaa, 0xbb, 0xcc, 0xdd, 0xee];
+            let stream = StreamContainer::new(bytes);
+            let mut iter = LeTryIter::<u16, _>::new(stream);
+
+            assert_eq!(iter.next(), Some(Ok(0xbbaa)));
+            assert_eq!(iter.next(), Some(Ok(0xddcc)));
+            assert_eq!(
+                iter.next(),
+                Some(Err(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 4,
+                    data_len: 5,
+                }))
+            );
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    mod be_try_iter {
+        use super::*;
+
+        #[test]
+        fn should_iterate() {
+            let bytes: [u8; 4] = [0xaa, 0xbb, 0xcc, 0xdd];
+            let stream = StreamContainer::new(bytes);
+            let result: ReaderResult<Vec<u16>> = BeTryIter::new(stream).collect();
+            assert_eq!(result, Ok(alloc::vec![0xaabb, 0xccdd]))
+        }
+
+        #[test]
+        fn should_return_the_error_when_collecting() {
+            let bytes: [u8; 3] = [0xaa, 0xbb, 0xcc];
+            let stream = StreamContainer::new(bytes);
+            let result: ReaderResult<Vec<u16>> = BeTryIter::new(stream).collect();
+            assert!(result.is_err());
+        }
     }
 }
@@ -1,14 +1,35 @@
+mod bit_reader;
+pub use bit_reader::*;
+
+mod checksum;
+pub use checksum::*;
+
 mod container;
 pub use container::*;
 
+#[cfg(feature = "embedded-io")]
+mod embedded;
+
 mod cursor;
 pub use cursor::*;
 
+mod guard;
+pub use guard::*;
+
 mod iter;
 pub use iter::*;
 
+mod patcher;
+pub use patcher::*;
+
 mod reader;
 pub use reader::*;
 
+#[cfg(feature = "std")]
+mod std_io;
+
+mod take;
+pub use take::*;
+
 mod writer;
 pub use writer::*;
@@ -0,0 +1,162 @@
+use crate::{Cursor, EndianWrite, Reader, Writer, WriterResult};
+
+/// Overwrites values in an existing buffer, such as fixing up offsets or version numbers
+/// in data that was already serialized.
+///
+/// Unlike [crate::StreamWriter] on a growable writer, a patcher never grows the buffer.
+/// Writes past the end return an error and leave the index unchanged.
+pub struct Patcher<'a> {
+    data: &'a mut [u8],
+    index: usize,
+}
+
+impl<'a> Patcher<'a> {
+    #[inline(always)]
+    pub fn new(data: &'a mut [u8]) -> Self {
+        Self { data, index: 0 }
+    }
+
+    /// Sets the index that the next patch will be written to.
+    #[inline(always)]
+    pub fn seek(&mut self, offset: usize) {
+        self.index = offset;
+    }
+
+    /// Writes a little endian value at the current index and advances past it.
+    #[inline(always)]
+    pub fn patch_le<T: EndianWrite>(&mut self, value: &T) -> WriterResult<usize> {
+        let bytes_written = self.data.write_le(self.index, value)?;
+        self.index += bytes_written;
+        Ok(bytes_written)
+    }
+
+    /// Same as [Patcher::patch_le], but writes a big endian value.
+    #[inline(always)]
+    pub fn patch_be<T: EndianWrite>(&mut self, value: &T) -> WriterResult<usize> {
+        let bytes_written = self.data.write_be(self.index, value)?;
+        self.index += bytes_written;
+        Ok(bytes_written)
+    }
+
+    /// Writes bytes at the current index and advances past them.
+    #[inline(always)]
+    pub fn patch_bytes(&mut self, bytes: &[u8]) -> WriterResult<usize> {
+        let bytes_written = self.data.write_bytes(self.index, bytes)?;
+        self.index += bytes_written;
+        Ok(bytes_written)
+    }
+
+    /// Consumes the patcher and returns the patched buffer.
+    #[inline(always)]
+    pub fn into_inner(self) -> &'a mut [u8] {
+        self.data
+    }
+}
+
+impl<'a> Reader for Patcher<'a> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        self.data
+    }
+}
+
+impl<'a> Writer for Patcher<'a> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self.data
+    }
+}
+
+impl<'a> Cursor for Patcher<'a> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.index
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error;
+
+    mod patch_le {
+        use super::*;
+
+        #[test]
+        fn should_overwrite_values_at_the_index() {
+            let mut bytes = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+            let mut patcher = Patcher::new(&mut bytes);
+            patcher.seek(2);
+
+            assert_eq!(patcher.patch_le(&0xaabbu16), Ok(2));
+            assert_eq!(patcher.get_index(), 4);
+            assert_eq!(patcher.patch_le(&0xccu8), Ok(1));
+            assert_eq!(bytes, [0x11, 0x22, 0xbb, 0xaa, 0xcc, 0x66]);
+        }
+
+        #[test]
+        fn should_return_error_instead_of_growing() {
+            let mut bytes = [0x11, 0x22, 0x33, 0x44];
+            let mut patcher = Patcher::new(&mut bytes);
+            patcher.seek(2);
+            let error = patcher
+                .patch_le(&0xaabbccddu32)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 2,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(patcher.get_index(), 2);
+            assert_eq!(patcher.into_inner(), [0x11, 0x22, 0x33, 0x44]);
+        }
+    }
+
+    mod patch_be {
+        use super::*;
+
+        #[test]
+        fn should_overwrite_values_at_the_index() {
+            let mut bytes = [0x11, 0x22, 0x33, 0x44];
+            let mut patcher = Patcher::new(&mut bytes);
+            patcher.seek(1);
+
+            assert_eq!(patcher.patch_be(&0xaabbu16), Ok(2));
+            assert_eq!(bytes, [0x11, 0xaa, 0xbb, 0x44]);
+        }
+    }
+
+    mod patch_bytes {
+        use super::*;
+
+        #[test]
+        fn should_overwrite_bytes_at_the_index() {
+            let mut bytes = [0x11, 0x22, 0x33, 0x44];
+            let mut patcher = Patcher::new(&mut bytes);
+            patcher.seek(3);
+
+            assert_eq!(patcher.patch_bytes(&[0xaa]), Ok(1));
+            assert_eq!(patcher.get_index(), 4);
+            assert_eq!(bytes, [0x11, 0x22, 0x33, 0xaa]);
+        }
+
+        #[test]
+        fn should_return_error_instead_of_growing() {
+            let mut bytes = [0x11, 0x22, 0x33, 0x44];
+            let mut patcher = Patcher::new(&mut bytes);
+            patcher.seek(3);
+
+            assert!(patcher.patch_bytes(&[0xaa, 0xbb]).is_err());
+            assert_eq!(patcher.get_index(), 3);
+        }
+    }
+}
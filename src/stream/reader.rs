@@ -1,13 +1,161 @@
+#[cfg(feature = "alloc")]
+use super::checksum::{Checksum, Crc16};
 use super::{
-    cursor::Cursor,
-    iter::{BeIter, LeIter},
+    cursor::{padding_to_align, Cursor},
+    guard::CursorGuard,
+    iter::{BeIter, BeTryIter, LeIter, LeTryIter},
+    take::TakenStream,
 };
-use crate::{EndianRead, Reader, ReaderResult};
-use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use crate::ReadOutput;
+use crate::{EndianRead, Error, Reader, ReaderResult};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::TryInto;
+use core::ops::RangeInclusive;
 use safe_transmute::TriviallyTransmutable;
 
 /// An interface to read values as a stream.
 pub trait StreamReader: Reader + Cursor + Sized {
+    /// Returns the number of bytes left from the current index,
+    /// or 0 if the index is past the end of the data.
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        self.get_slice().len().saturating_sub(self.get_index())
+    }
+
+    /// Returns the bytes left from the current index without copying them,
+    /// or an empty slice if the index is at or past the end of the data.
+    #[inline(always)]
+    fn remaining_slice(&self) -> &[u8] {
+        self.get_slice_at_offset(self.get_index())
+    }
+
+    /// Same as [StreamReader::remaining_slice], but also moves the index to the end of the data.
+    #[inline(always)]
+    fn take_rest(&mut self) -> &[u8] {
+        let index = self.get_index();
+        self.set_index(self.get_slice().len());
+        self.get_slice_at_offset(index)
+    }
+
+    /// Returns true if there are no bytes left to read from the current index.
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Same as [Cursor::increment_by], but returns [Error::InvalidSize]
+    /// and leaves the index unchanged if the new index would be past the end of the data.
+    #[inline(always)]
+    fn try_increment_by(&mut self, count: usize) -> ReaderResult<()> {
+        let index = self.get_index();
+        let data_len = self.get_slice().len();
+
+        match index.checked_add(count) {
+            Some(new_index) if new_index <= data_len => {
+                self.set_index(new_index);
+                Ok(())
+            }
+            _ => Err(Error::InvalidSize {
+                wanted_size: count,
+                offset: index,
+                data_len,
+            }),
+        }
+    }
+
+    /// Same as [Cursor::set_index], but returns [Error::InvalidSize]
+    /// and leaves the index unchanged if the index is past the end of the data.
+    /// Setting the index to the length of the data is allowed.
+    #[inline(always)]
+    fn try_set_index(&mut self, index: usize) -> ReaderResult<()> {
+        let data_len = self.get_slice().len();
+
+        if index > data_len {
+            return Err(Error::InvalidSize {
+                wanted_size: 0,
+                offset: index,
+                data_len,
+            });
+        }
+
+        self.set_index(index);
+        Ok(())
+    }
+
+    /// Same as [Cursor::align_to], but returns [Error::InvalidAlignment] if the alignment
+    /// is not a power of two and [Error::InvalidSize] if the aligned index would be past the end of the data.
+    /// The index is unchanged if the alignment fails.
+    #[inline(always)]
+    fn try_align_to(&mut self, alignment: usize) -> ReaderResult<usize> {
+        let index = self.get_index();
+
+        if !alignment.is_power_of_two() {
+            return Err(Error::InvalidAlignment {
+                wanted_size: 0,
+                source_size: self.get_slice().len(),
+                source_offset: index,
+                alignment,
+            });
+        }
+
+        let padding = padding_to_align(index, alignment);
+        self.try_increment_by(padding)?;
+        Ok(padding)
+    }
+
+    /// Same as [StreamReader::try_increment_by], but returns the skipped bytes,
+    /// such as to record a reserved region.
+    #[inline(always)]
+    fn skip_stream(&mut self, count: usize) -> ReaderResult<&[u8]> {
+        let index = self.get_index();
+        self.try_increment_by(count)?;
+        Ok(&self.get_slice()[index..index + count])
+    }
+
+    /// Sets the index back to the start of the data.
+    #[inline(always)]
+    fn rewind(&mut self) {
+        self.set_index(0);
+    }
+
+    /// Saves the current index and returns a guard that restores it when dropped,
+    /// unless [CursorGuard::commit] is called.
+    #[inline(always)]
+    fn save_position(&mut self) -> CursorGuard<'_, Self> {
+        CursorGuard::new(self)
+    }
+
+    /// Returns a stream that reads from the current index,
+    /// but ends `limit` bytes later even if the data is longer.
+    #[inline(always)]
+    fn take_stream(self, limit: usize) -> TakenStream<Self> {
+        TakenStream::new(self, limit)
+    }
+
+    /// Sets the index to `offset_from_end` bytes before the end of the data.
+    /// The index is set to 0 if the offset is larger than the data.
+    #[inline(always)]
+    fn seek_from_end(&mut self, offset_from_end: usize) {
+        let index = self.get_slice().len().saturating_sub(offset_from_end);
+        self.set_index(index);
+    }
+
+    /// Moves the index forward or backward by `delta` bytes.
+    /// Moving backward past the start of the data sets the index to 0.
+    #[inline(always)]
+    fn seek_relative(&mut self, delta: isize) {
+        let index = self.get_index();
+        let index = if delta < 0 {
+            index.saturating_sub(delta.unsigned_abs())
+        } else {
+            index.saturating_add(delta as usize)
+        };
+        self.set_index(index);
+    }
+
     /// Same as [Reader::read], but uses the current stream instead of an offset.
     #[inline(always)]
     fn read_stream<T: TriviallyTransmutable + Default>(&mut self) -> ReaderResult<T> {
@@ -54,7 +202,91 @@ pub trait StreamReader: Reader + Cursor + Sized {
         self.default_read_be(index)
     }
 
+    /// Same as [StreamReader::read_stream_le] or [StreamReader::read_stream_be],
+    /// depending on the target's native endianness.
+    #[inline(always)]
+    fn read_stream_ne<T: EndianRead>(&mut self) -> ReaderResult<T> {
+        if cfg!(target_endian = "big") {
+            self.read_stream_be()
+        } else {
+            self.read_stream_le()
+        }
+    }
+
+    /// Same as [Reader::read_le_in_range], but uses the current stream instead of an offset.
+    ///
+    /// The stream is not advanced if the read fails or the value is out of range.
+    #[inline(always)]
+    fn read_stream_le_in_range<T: EndianRead + PartialOrd>(
+        &mut self,
+        range: RangeInclusive<T>,
+    ) -> ReaderResult<T> {
+        let index = self.get_index();
+        let read_value = self.read_le_with_output::<T>(index)?;
+        let read_bytes = read_value.get_read_bytes();
+        let value = read_value.into_data();
+
+        if !range.contains(&value) {
+            return Err(Error::InvalidRead {
+                message: "Value out of range",
+            });
+        }
+
+        self.increment_by(read_bytes);
+        Ok(value)
+    }
+
+    /// Same as [StreamReader::read_stream_le_in_range], but reads a big endian value.
+    #[inline(always)]
+    fn read_stream_be_in_range<T: EndianRead + PartialOrd>(
+        &mut self,
+        range: RangeInclusive<T>,
+    ) -> ReaderResult<T> {
+        let index = self.get_index();
+        let read_value = self.read_be_with_output::<T>(index)?;
+        let read_bytes = read_value.get_read_bytes();
+        let value = read_value.into_data();
+
+        if !range.contains(&value) {
+            return Err(Error::InvalidRead {
+                message: "Value out of range",
+            });
+        }
+
+        self.increment_by(read_bytes);
+        Ok(value)
+    }
+
+    /// Same as [StreamReader::read_stream_le], but does not advance the stream.
+    #[inline(always)]
+    fn peek_stream_le<T: EndianRead>(&self) -> ReaderResult<T> {
+        self.read_le(self.get_index())
+    }
+
+    /// Same as [StreamReader::read_stream_be], but does not advance the stream.
+    #[inline(always)]
+    fn peek_stream_be<T: EndianRead>(&self) -> ReaderResult<T> {
+        self.read_be(self.get_index())
+    }
+
+    /// Reads a [u8] tag, then passes the tag and the stream to `parse` to read the tagged value,
+    /// such as the variant of a tagged union.
+    ///
+    /// The index is restored to before the tag if reading the tag or `parse` fails.
+    #[inline(always)]
+    fn read_tagged<T, F>(&mut self, parse: F) -> ReaderResult<T>
+    where
+        F: FnOnce(u8, &mut Self) -> ReaderResult<T>,
+    {
+        let mut guard = self.save_position();
+        let tag = guard.read_stream_le::<u8>()?;
+        let value = parse(tag, &mut guard)?;
+        guard.commit();
+        Ok(value)
+    }
+
     /// Same as [Reader::read_byte_vec], but uses the current stream instead of an offset.
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn read_byte_stream(&mut self, size: usize) -> ReaderResult<Vec<u8>> {
         let index = self.swap_incremented_index(size);
@@ -62,12 +294,134 @@ pub trait StreamReader: Reader + Cursor + Sized {
     }
 
     /// Same as [Reader::default_read_byte_vec], but returns a default value if the read is invalid.
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn default_read_byte_stream(&mut self, size: usize) -> Vec<u8> {
         let index = self.swap_incremented_index(size);
         self.default_read_byte_vec(index, size)
     }
 
+    /// Reads exactly `count` little endian values from the stream.
+    ///
+    /// The index is unchanged if any value fails to read.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_vec_le<T: EndianRead>(&mut self, count: usize) -> ReaderResult<Vec<T>> {
+        self.read_vec_stream_le_with_progress(count, 0, |_, _| {})
+    }
+
+    /// Same as [StreamReader::read_stream_vec_le], but reads big endian values.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_vec_be<T: EndianRead>(&mut self, count: usize) -> ReaderResult<Vec<T>> {
+        self.read_vec_stream_be_with_progress(count, 0, |_, _| {})
+    }
+
+    /// Reads a frame of `body_len` bytes followed by a little endian CRC-16 of the body,
+    /// and returns the body if the CRC matches. [Crc16::default] is used for the CRC.
+    ///
+    /// Returns [Error::InvalidRead] if the CRC doesn't match.
+    /// The index is unchanged if the read fails.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_frame_le(&mut self, body_len: usize) -> ReaderResult<Vec<u8>> {
+        read_frame(self, body_len, |guard| guard.read_stream_le())
+    }
+
+    /// Same as [StreamReader::read_frame_le], but reads a big endian CRC.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_frame_be(&mut self, body_len: usize) -> ReaderResult<Vec<u8>> {
+        read_frame(self, body_len, |guard| guard.read_stream_be())
+    }
+
+    /// Reads `count` little endian values from the stream, calling `progress` every `every` values
+    /// with the number of values read and the number of bytes consumed so far.
+    ///
+    /// The callback is never called if `every` is 0.
+    /// The index is unchanged if any value fails to read,
+    /// even if the callback was already called for the values before it.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_vec_stream_le_with_progress<T: EndianRead, F: FnMut(usize, usize)>(
+        &mut self,
+        count: usize,
+        every: usize,
+        progress: F,
+    ) -> ReaderResult<Vec<T>> {
+        read_vec_stream_with_progress(self, count, every, progress, Self::read_stream_le)
+    }
+
+    /// Same as [StreamReader::read_vec_stream_le_with_progress], but reads big endian values.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_vec_stream_be_with_progress<T: EndianRead, F: FnMut(usize, usize)>(
+        &mut self,
+        count: usize,
+        every: usize,
+        progress: F,
+    ) -> ReaderResult<Vec<T>> {
+        read_vec_stream_with_progress(self, count, every, progress, Self::read_stream_be)
+    }
+
+    /// Same as [Reader::read_until_byte], but uses the current stream instead of an offset
+    /// and advances the stream past the delimiter.
+    ///
+    /// The stream is not advanced if the delimiter is not found.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_byte_stream_until(&mut self, delimiter: u8) -> ReaderResult<Vec<u8>> {
+        let index = self.get_index();
+        let read_value = self.read_until_byte(index, delimiter)?;
+        let read_bytes = read_value.get_read_bytes();
+        let bytes = read_value.into_data().to_vec();
+        self.increment_by(read_bytes);
+        Ok(bytes)
+    }
+
+    /// Same as [StreamReader::read_byte_stream_until], but the returned bytes include the delimiter.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_byte_stream_until_inclusive(&mut self, delimiter: u8) -> ReaderResult<Vec<u8>> {
+        let index = self.get_index();
+        let read_bytes = self.read_until_byte(index, delimiter)?.get_read_bytes();
+        let bytes = self.get_slice_of_size(index, read_bytes)?.to_vec();
+        self.increment_by(read_bytes);
+        Ok(bytes)
+    }
+
+    /// Same as [Reader::read_cstr], but uses the current stream instead of an offset
+    /// and advances the stream past the null terminator.
+    ///
+    /// The stream is not advanced if the read fails.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_cstr(&mut self) -> ReaderResult<String> {
+        let index = self.get_index();
+        let value = self.read_cstr(index)?;
+        self.increment_by(value.len() + 1);
+        Ok(value)
+    }
+
+    /// Reads a little endian length prefix of type `L`, then reads that many bytes as a UTF-8 string.
+    ///
+    /// Returns [Error::InvalidSize] if the length exceeds the remaining data,
+    /// [Error::InvalidRead] if the length does not fit in a `usize`,
+    /// and [Error::InvalidUtf8] if the string is not valid UTF-8.
+    /// The stream is not advanced if the read fails.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_string_prefixed_le<L: EndianRead + TryInto<usize>>(&mut self) -> ReaderResult<String> {
+        read_string_prefixed(self, Self::read_le_with_output::<L>)
+    }
+
+    /// Same as [StreamReader::read_string_prefixed_le], but reads a big endian length prefix.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_string_prefixed_be<L: EndianRead + TryInto<usize>>(&mut self) -> ReaderResult<String> {
+        read_string_prefixed(self, Self::read_be_with_output::<L>)
+    }
+
     #[inline(always)]
     fn into_le_iter<Item: EndianRead>(self) -> LeIter<Item, Self> {
         LeIter::new(self)
@@ -77,10 +431,107 @@ pub trait StreamReader: Reader + Cursor + Sized {
     fn into_be_iter<Item: EndianRead>(self) -> BeIter<Item, Self> {
         BeIter::new(self)
     }
+
+    /// Same as [StreamReader::into_le_iter], but yields read errors.
+    #[inline(always)]
+    fn into_le_try_iter<Item: EndianRead>(self) -> LeTryIter<Item, Self> {
+        LeTryIter::new(self)
+    }
+
+    /// Same as [StreamReader::into_be_iter], but yields read errors.
+    #[inline(always)]
+    fn into_be_try_iter<Item: EndianRead>(self) -> BeTryIter<Item, Self> {
+        BeTryIter::new(self)
+    }
 }
 
 impl<T> StreamReader for T where T: Reader + Cursor {}
 
+/// Reads `count` values with `read`, reporting progress like
+/// [StreamReader::read_vec_stream_le_with_progress].
+///
+/// The capacity is bounded by the remaining bytes so a large count
+/// can't reserve more memory than the data could fill.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn read_vec_stream_with_progress<S: StreamReader, T: EndianRead, F: FnMut(usize, usize)>(
+    stream: &mut S,
+    count: usize,
+    every: usize,
+    mut progress: F,
+    read: fn(&mut S) -> ReaderResult<T>,
+) -> ReaderResult<Vec<T>> {
+    let mut guard = stream.save_position();
+    let start = guard.get_saved_index();
+    let max_count = match T::FIXED_SIZE {
+        Some(size) if size > 0 => guard.remaining() / size,
+        _ => guard.remaining(),
+    };
+    let mut values = Vec::with_capacity(count.min(max_count));
+
+    for read_count in 1..=count {
+        values.push(read(&mut guard)?);
+
+        if every != 0 && read_count % every == 0 {
+            progress(read_count, guard.get_index() - start);
+        }
+    }
+
+    guard.commit();
+    Ok(values)
+}
+
+/// Reads a frame body followed by a CRC read with `read_crc`,
+/// like [StreamReader::read_frame_le].
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn read_frame<S: StreamReader>(
+    stream: &mut S,
+    body_len: usize,
+    read_crc: fn(&mut CursorGuard<'_, S>) -> ReaderResult<u16>,
+) -> ReaderResult<Vec<u8>> {
+    let mut guard = stream.save_position();
+    let body = guard.read_byte_stream(body_len)?;
+    let crc = read_crc(&mut guard)?;
+    let mut checksum = Crc16::default();
+    checksum.update(&body);
+
+    if checksum.finalize() != u32::from(crc) {
+        return Err(Error::InvalidRead {
+            message: "Invalid checksum",
+        });
+    }
+
+    guard.commit();
+    Ok(body)
+}
+
+/// Reads a length prefix with `read`, then reads that many bytes as a UTF-8 string,
+/// like [StreamReader::read_string_prefixed_le].
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn read_string_prefixed<S: StreamReader, L: EndianRead + TryInto<usize>>(
+    stream: &mut S,
+    read: fn(&S, usize) -> ReaderResult<ReadOutput<L>>,
+) -> ReaderResult<String> {
+    let index = stream.get_index();
+    let prefix = read(stream, index)?;
+    let prefix_size = prefix.get_read_bytes();
+    let length = prefix
+        .into_data()
+        .try_into()
+        .map_err(|_| Error::InvalidRead {
+            message: "String length does not fit in usize",
+        })?;
+    let string_offset = index + prefix_size;
+    let bytes = stream.get_slice_of_size(string_offset, length)?;
+    let value = String::from_utf8(bytes.to_vec()).map_err(|error| Error::InvalidUtf8 {
+        offset: string_offset + error.utf8_error().valid_up_to(),
+    })?;
+    stream.increment_by(prefix_size + length);
+    Ok(value)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -112,232 +563,1183 @@ mod test {
         }
     }
 
-    mod read_stream {
+    mod remaining {
         use super::*;
-        use crate::Error;
 
         #[test]
-        fn should_return_a_value() {
-            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
-            let value = reader
-                .read_stream::<u32>()
-                .expect("Read should have been successful.");
+        fn should_return_the_bytes_left() {
+            let mut reader = MockStream::new([0; 8]);
+            assert_eq!(reader.remaining(), 8);
 
-            assert_eq!(value, 0x11223344);
-            assert_eq!(reader.get_index(), 4);
+            reader.set_index(6);
+            assert_eq!(reader.remaining(), 2);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
-            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
+        fn should_return_0_if_the_index_is_past_the_end() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(10);
+            assert_eq!(reader.remaining(), 0);
+        }
+    }
+
+    mod remaining_slice {
+        use super::*;
+
+        #[test]
+        fn should_return_the_bytes_left() {
+            let mut reader = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            reader.set_index(5);
+            assert_eq!(reader.remaining_slice(), [6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_an_empty_slice_at_or_past_the_end() {
+            let mut reader = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
             reader.set_index(8);
-            let error = reader
-                .read_stream::<u32>()
-                .expect_err("Length should have been too large");
+            assert!(reader.remaining_slice().is_empty());
 
-            assert_eq!(
-                error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 8,
-                    data_len: 8,
-                }
-            );
+            reader.set_index(10);
+            assert!(reader.remaining_slice().is_empty());
         }
+    }
+
+    mod take_rest {
+        use super::*;
 
         #[test]
-        fn should_return_error_if_alignment_is_invalid() {
-            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
-            reader.set_index(3);
-            let error = reader
-                .read_stream::<u32>()
-                .expect_err("Alignment should have been invalid");
+        fn should_return_the_bytes_left_and_move_to_the_end() {
+            let mut reader = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            reader.set_index(5);
 
-            assert_eq!(
-                error,
-                Error::InvalidAlignment {
-                    wanted_size: 4,
-                    source_size: 4,
-                    source_offset: 3,
-                }
-            );
+            assert_eq!(reader.take_rest(), [6, 7, 8]);
+            assert_eq!(reader.get_index(), 8);
+            assert!(reader.is_empty());
+        }
+
+        #[test]
+        fn should_return_an_empty_slice_past_the_end() {
+            let mut reader = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            reader.set_index(10);
+
+            assert!(reader.take_rest().is_empty());
+            assert_eq!(reader.get_index(), 8);
         }
     }
 
-    mod default_read_stream {
+    mod is_empty {
         use super::*;
 
         #[test]
-        fn should_return_a_value() {
-            let mut reader = MockStream::new(u64::to_ne_bytes(0x11223344aabbccdd));
-            reader.set_index(4);
-            let value = reader.default_read_stream::<u32>();
-            assert_eq!(value, 0x11223344);
+        fn should_return_false_if_there_are_bytes_left() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(7);
+            assert!(!reader.is_empty());
         }
 
         #[test]
-        fn should_return_default_if_size_is_too_large_for_offset() {
-            let mut reader = MockStream::new(u64::to_ne_bytes(0x11223344aabbccdd));
+        fn should_return_true_at_or_past_the_end() {
+            let mut reader = MockStream::new([0; 8]);
             reader.set_index(8);
-            let value = reader.default_read_stream::<u32>();
-            assert_eq!(value, u32::default());
+            assert!(reader.is_empty());
+
+            reader.set_index(9);
+            assert!(reader.is_empty());
         }
 
         #[test]
-        fn should_return_default_if_alignment_is_invalid() {
-            let mut reader = MockStream::new(u64::to_ne_bytes(0x11223344aabbccdd));
-            reader.set_index(2);
-            let value = reader.default_read_stream::<u32>();
-            assert_eq!(value, u32::default());
+        fn should_end_a_read_loop() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut values = Vec::new();
+
+            while !reader.is_empty() {
+                values.push(reader.read_stream_le::<u16>().unwrap());
+            }
+
+            assert_eq!(values, [0x2211, 0x4433, 0xbbaa, 0xddcc]);
         }
     }
 
-    mod read_stream_le {
+    mod try_increment_by {
         use super::*;
-        use crate::{Error, ReadOutput};
 
         #[test]
-        fn should_return_a_value() {
-            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(4);
-            let value = reader
-                .read_stream_le::<u32>()
-                .expect("Read should have been successful.");
+        fn should_increment_the_index() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(2);
 
-            assert_eq!(value, 0xddccbbaa);
+            assert_eq!(reader.try_increment_by(6), Ok(()));
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
-            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(8);
+        fn should_return_error_if_the_index_would_be_past_the_end() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(2);
             let error = reader
-                .read_stream_le::<u32>()
-                .expect_err("Length should have been too large");
+                .try_increment_by(7)
+                .expect_err("Increment should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 8,
+                    wanted_size: 7,
+                    offset: 2,
                     data_len: 8,
                 }
             );
+            assert_eq!(reader.get_index(), 2);
         }
+    }
 
-        #[derive(Debug, PartialEq)]
-        struct Sum(u8);
+    mod try_set_index {
+        use super::*;
 
-        impl EndianRead for Sum {
-            fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
-                let sum = bytes[0].wrapping_add(bytes[1]);
-                Ok(ReadOutput::new(Sum(sum), 2))
-            }
+        #[test]
+        fn should_set_the_index_to_the_end() {
+            let mut reader = MockStream::new([0; 8]);
 
-            fn try_read_be(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
-                unimplemented!()
-            }
+            assert_eq!(reader.try_set_index(8), Ok(()));
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[test]
-        fn should_read_values_with_dynamic_read_lengths() {
-            let mut reader = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
-            let value = reader
-                .read_stream_le::<Sum>()
-                .expect("Read should have been successful.");
+        fn should_return_error_if_the_index_is_past_the_end() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(2);
 
-            assert_eq!(value, Sum(0x33));
+            assert_eq!(
+                reader.try_set_index(9),
+                Err(Error::InvalidSize {
+                    wanted_size: 0,
+                    offset: 9,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(reader.get_index(), 2);
         }
+    }
+
+    mod try_align_to {
+        use super::*;
 
         #[test]
-        fn should_read_multple_values_with_dynamic_read_lengths() {
-            let mut reader = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
+        fn should_align_the_index() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(3);
 
-            let value = reader
-                .read_stream_le::<Sum>()
-                .expect("Read should have been successful.");
-            assert_eq!(value, Sum(0x33));
+            assert_eq!(reader.try_align_to(4), Ok(1));
+            assert_eq!(reader.get_index(), 4);
+            assert_eq!(reader.try_align_to(4), Ok(0));
+        }
+
+        #[test]
+        fn should_return_error_if_alignment_is_not_a_power_of_two() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(3);
+
+            for alignment in [0, 3] {
+                assert_eq!(
+                    reader.try_align_to(alignment),
+                    Err(Error::InvalidAlignment {
+                        wanted_size: 0,
+                        source_size: 8,
+                        source_offset: 3,
+                        alignment,
+                    })
+                );
+            }
+            assert_eq!(reader.get_index(), 3);
+        }
+
+        #[test]
+        fn should_return_error_if_the_aligned_index_is_past_the_end() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(7);
+
+            assert_eq!(
+                reader.try_align_to(16),
+                Err(Error::InvalidSize {
+                    wanted_size: 9,
+                    offset: 7,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(reader.get_index(), 7);
+        }
+    }
+
+    mod skip_stream {
+        use super::*;
+
+        #[test]
+        fn should_return_the_skipped_bytes() {
+            let mut reader = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            reader.set_index(2);
+
+            assert_eq!(reader.skip_stream(3), Ok([3, 4, 5].as_slice()));
+            assert_eq!(reader.get_index(), 5);
+        }
+
+        #[test]
+        fn should_return_error_if_there_are_not_enough_bytes() {
+            let mut reader = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            reader.set_index(6);
+
+            assert_eq!(
+                reader.skip_stream(3),
+                Err(Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod rewind {
+        use super::*;
+
+        #[test]
+        fn should_set_the_index_to_0() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(6);
+            reader.rewind();
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod save_position {
+        use super::*;
+
+        #[test]
+        fn should_restore_the_index_after_the_guard_drops() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+
+            {
+                let mut guard = reader.save_position();
+                assert_eq!(guard.read_stream_le::<u32>(), Ok(0xbbaa4433));
+            }
+
+            assert_eq!(reader.get_index(), 2);
+        }
+
+        #[test]
+        fn should_keep_the_index_after_commit() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+            let mut guard = reader.save_position();
+            assert_eq!(guard.read_stream_le::<u32>(), Ok(0x44332211));
+            guard.commit();
+
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_restore_the_index_after_a_failed_parse() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
 
+            let parse = |reader: &mut MockStream| -> ReaderResult<u64> {
+                let mut guard = reader.save_position();
+                let first = guard.read_stream_le::<u32>()?;
+                let second = guard.read_stream_le::<u64>()?;
+                guard.commit();
+                Ok(first as u64 + second)
+            };
+
+            assert!(parse(&mut reader).is_err());
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod read_tagged {
+        use super::*;
+
+        fn parse(tag: u8, reader: &mut MockStream) -> ReaderResult<u32> {
+            match tag {
+                1 => reader.read_stream_le::<u16>().map(u32::from),
+                2 => reader.read_stream_le::<u32>(),
+                _ => Err(Error::InvalidRead {
+                    message: "Unknown tag",
+                }),
+            }
+        }
+
+        #[test]
+        fn should_pass_the_tag_to_the_parser() {
+            let mut reader = MockStream::new([0x01, 0x22, 0x33, 0x02, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+            assert_eq!(reader.read_tagged(parse), Ok(0x3322));
+            assert_eq!(reader.read_tagged(parse), Ok(0xddccbbaa));
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_restore_the_index_if_the_parser_fails() {
+            let mut reader = MockStream::new([0x01, 0x22, 0x33, 0x03, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(3);
+
+            assert_eq!(
+                reader.read_tagged(parse),
+                Err(Error::InvalidRead {
+                    message: "Unknown tag",
+                })
+            );
+            assert_eq!(reader.get_index(), 3);
+        }
+
+        #[test]
+        fn should_restore_the_index_if_the_value_is_too_short() {
+            let mut reader = MockStream::new([0x01, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0x02, 0xdd]);
+            reader.set_index(6);
+
+            assert!(reader.read_tagged(parse).is_err());
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod seek_from_end {
+        use super::*;
+
+        #[test]
+        fn should_seek_relative_to_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.seek_from_end(4);
+
+            assert_eq!(reader.get_index(), 4);
+            assert_eq!(reader.read_stream_le::<u32>(), Ok(0xddccbbaa));
+        }
+
+        #[test]
+        fn should_seek_to_0_if_the_offset_is_larger_than_the_data() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(5);
+            reader.seek_from_end(10);
+
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod seek_relative {
+        use super::*;
+
+        #[test]
+        fn should_seek_forward() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(2);
+            reader.seek_relative(3);
+
+            assert_eq!(reader.get_index(), 5);
+        }
+
+        #[test]
+        fn should_seek_backward() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(6);
+            reader.seek_relative(-4);
+
+            assert_eq!(reader.get_index(), 2);
+        }
+
+        #[test]
+        fn should_saturate_to_0() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(2);
+            reader.seek_relative(-5);
+
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod read_stream {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
             let value = reader
-                .read_stream_le::<Sum>()
+                .read_stream::<u32>()
                 .expect("Read should have been successful.");
-            assert_eq!(value, Sum(0x65));
+
+            assert_eq!(value, 0x11223344);
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
+            reader.set_index(8);
+            let error = reader
+                .read_stream::<u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_alignment_is_invalid() {
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
+            reader.set_index(3);
+            let error = reader
+                .read_stream::<u32>()
+                .expect_err("Alignment should have been invalid");
+
+            assert_eq!(
+                error,
+                Error::InvalidAlignment {
+                    wanted_size: 4,
+                    source_size: 4,
+                    source_offset: 3,
+                    alignment: 4,
+                }
+            );
         }
     }
 
-    mod default_read_stream_le {
+    mod default_read_stream {
         use super::*;
 
         #[test]
         fn should_return_a_value() {
-            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x11223344aabbccdd));
             reader.set_index(4);
-            let value = reader.default_read_stream_le::<u32>();
-            assert_eq!(value, 0xddccbbaa);
+            let value = reader.default_read_stream::<u32>();
+            assert_eq!(value, 0x11223344);
         }
 
         #[test]
         fn should_return_default_if_size_is_too_large_for_offset() {
-            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(6);
-            let value = reader.default_read_stream_le::<u32>();
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x11223344aabbccdd));
+            reader.set_index(8);
+            let value = reader.default_read_stream::<u32>();
+            assert_eq!(value, u32::default());
+        }
+
+        #[test]
+        fn should_return_default_if_alignment_is_invalid() {
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x11223344aabbccdd));
+            reader.set_index(2);
+            let value = reader.default_read_stream::<u32>();
             assert_eq!(value, u32::default());
         }
     }
 
-    mod read_byte_stream {
+    mod read_stream_le {
         use super::*;
-        use crate::Error;
-        use alloc::vec;
+        use crate::{Error, ReadOutput};
 
         #[test]
         fn should_return_a_value() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             reader.set_index(4);
             let value = reader
-                .read_byte_stream(3)
+                .read_stream_le::<u32>()
                 .expect("Read should have been successful.");
 
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            assert_eq!(value, 0xddccbbaa);
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(6);
+            reader.set_index(8);
             let error = reader
-                .read_byte_stream(4)
+                .read_stream_le::<u32>()
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
                     wanted_size: 4,
-                    offset: 6,
+                    offset: 8,
                     data_len: 8,
                 }
             );
         }
+
+        #[derive(Debug, PartialEq)]
+        struct Sum(u8);
+
+        impl EndianRead for Sum {
+            fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                let sum = bytes[0].wrapping_add(bytes[1]);
+                Ok(ReadOutput::new(Sum(sum), 2))
+            }
+
+            fn try_read_be(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[test]
+        fn should_read_values_with_dynamic_read_lengths() {
+            let mut reader = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
+            let value = reader
+                .read_stream_le::<Sum>()
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, Sum(0x33));
+        }
+
+        #[test]
+        fn should_read_multple_values_with_dynamic_read_lengths() {
+            let mut reader = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
+
+            let value = reader
+                .read_stream_le::<Sum>()
+                .expect("Read should have been successful.");
+            assert_eq!(value, Sum(0x33));
+
+            let value = reader
+                .read_stream_le::<Sum>()
+                .expect("Read should have been successful.");
+            assert_eq!(value, Sum(0x65));
+        }
     }
 
-    mod default_read_byte_stream {
+    mod default_read_stream_le {
         use super::*;
-        use alloc::vec;
 
         #[test]
         fn should_return_a_value() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             reader.set_index(4);
-            let value = reader.default_read_byte_stream(3);
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            let value = reader.default_read_stream_le::<u32>();
+            assert_eq!(value, 0xddccbbaa);
         }
 
         #[test]
         fn should_return_default_if_size_is_too_large_for_offset() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             reader.set_index(6);
-            let value = reader.default_read_byte_stream(4);
-            assert_eq!(value, vec![0, 0, 0, 0]);
+            let value = reader.default_read_stream_le::<u32>();
+            assert_eq!(value, u32::default());
+        }
+    }
+
+    mod read_stream_le_in_range {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value_and_advance() {
+            let mut reader = MockStream::new([0x64, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_stream_le_in_range::<u16>(0..=100), Ok(100));
+            assert_eq!(reader.read_stream_le_in_range::<u8>(0..=7), Ok(7));
+            assert_eq!(reader.get_index(), 3);
+        }
+
+        #[test]
+        fn should_not_advance_if_value_is_out_of_range() {
+            let mut reader = MockStream::new([0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            let error = reader
+                .read_stream_le_in_range::<u8>(0..=7)
+                .expect_err("Value should have been out of range");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Value out of range"
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod read_stream_ne {
+        use super::*;
+
+        #[test]
+        fn should_read_native_endian_values() {
+            let value = 0x11223344u32;
+            let bytes = value.to_ne_bytes();
+            let mut reader = MockStream::new([
+                bytes[0], bytes[1], bytes[2], bytes[3], 0xaa, 0xbb, 0xcc, 0xdd,
+            ]);
+
+            assert_eq!(reader.read_stream_ne::<u32>(), Ok(value));
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        #[cfg(target_endian = "little")]
+        fn should_read_little_endian_on_little_endian_targets() {
+            let mut reader = MockStream::new([0x44, 0x33, 0x22, 0x11, 0, 0, 0, 0]);
+            assert_eq!(reader.read_stream_ne::<u32>(), Ok(0x11223344));
+        }
+    }
+
+    mod read_stream_be_in_range {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value_and_advance() {
+            let mut reader = MockStream::new([0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            assert_eq!(reader.read_stream_be_in_range::<u16>(0..=100), Ok(100));
+            assert_eq!(reader.get_index(), 2);
+        }
+
+        #[test]
+        fn should_not_advance_if_value_is_out_of_range() {
+            let mut reader = MockStream::new([0x00, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            reader
+                .read_stream_be_in_range::<u16>(0..=100)
+                .expect_err("Value should have been out of range");
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod peek_stream_le {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_return_a_value_without_advancing() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader
+                .peek_stream_le::<u32>()
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0xddccbbaa);
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .peek_stream_le::<u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod peek_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value_without_advancing() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader
+                .peek_stream_be::<u32>()
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, 0xaabbccdd);
+            assert_eq!(reader.get_index(), 4);
+        }
+    }
+
+    mod read_byte_stream {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader
+                .read_byte_stream(3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .read_byte_stream(4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod default_read_byte_stream {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.default_read_byte_stream(3);
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_return_default_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let value = reader.default_read_byte_stream(4);
+            assert_eq!(value, vec![0, 0, 0, 0]);
+        }
+    }
+
+    mod read_stream_vec_le {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_read_count_values() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let values = reader
+                .read_stream_vec_le::<u16>(3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, vec![0x2211, 0x4433, 0xbbaa]);
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let error = reader
+                .read_stream_vec_le::<u16>(3)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_count_is_larger_than_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_stream_vec_le::<u16>(usize::MAX)
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod read_stream_vec_be {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_read_count_values() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let values = reader
+                .read_stream_vec_be::<u16>(3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, vec![0x1122, 0x3344, 0xaabb]);
+        }
+
+        #[test]
+        fn should_return_error_if_count_is_larger_than_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_stream_vec_be::<u16>(5)
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod read_frame_le {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_the_body_if_the_crc_matches() {
+            let mut reader = MockStream::new([0x31, 0x32, 0x33, 0x34, 0x35, 0x60, 0x45, 0xff]);
+            let body = reader
+                .read_frame_le(5)
+                .expect("Read should have been successful.");
+
+            assert_eq!(body, vec![0x31, 0x32, 0x33, 0x34, 0x35]);
+            assert_eq!(reader.get_index(), 7);
+        }
+
+        #[test]
+        fn should_return_error_if_the_crc_does_not_match() {
+            let mut reader = MockStream::new([0x31, 0x32, 0x33, 0x34, 0x35, 0x45, 0x60, 0xff]);
+
+            assert_eq!(
+                reader.read_frame_le(5),
+                Err(Error::InvalidRead {
+                    message: "Invalid checksum",
+                })
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+
+        #[test]
+        fn should_return_error_if_the_crc_is_missing() {
+            let mut reader = MockStream::new([0x31, 0x32, 0x33, 0x34, 0x35, 0x60, 0x45, 0xff]);
+            reader.set_index(2);
+
+            assert!(reader.read_frame_le(5).is_err());
+            assert_eq!(reader.get_index(), 2);
+        }
+    }
+
+    mod read_frame_be {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_the_body_if_the_crc_matches() {
+            let mut reader = MockStream::new([0x31, 0x32, 0x33, 0x34, 0x35, 0x45, 0x60, 0xff]);
+            let body = reader
+                .read_frame_be(5)
+                .expect("Read should have been successful.");
+
+            assert_eq!(body, vec![0x31, 0x32, 0x33, 0x34, 0x35]);
+        }
+    }
+
+    mod read_vec_stream_le_with_progress {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_read_values_and_report_progress() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut progress = vec![];
+            let values = reader
+                .read_vec_stream_le_with_progress::<u16, _>(4, 2, |read, consumed| {
+                    progress.push((read, consumed))
+                })
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, vec![0x2211, 0x4433, 0xbbaa, 0xddcc]);
+            assert_eq!(progress, vec![(2, 4), (4, 8)]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_report_progress_if_every_is_0() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut calls = 0;
+            reader
+                .read_vec_stream_le_with_progress::<u32, _>(2, 0, |_, _| calls += 1)
+                .expect("Read should have been successful.");
+
+            assert_eq!(calls, 0);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut progress = vec![];
+            let error = reader
+                .read_vec_stream_le_with_progress::<u32, _>(3, 1, |read, consumed| {
+                    progress.push((read, consumed))
+                })
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(progress, vec![(1, 4), (2, 8)]);
+            assert_eq!(reader.get_index(), 0);
+        }
+
+        #[test]
+        fn should_return_error_if_the_count_is_larger_than_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_vec_stream_le_with_progress::<u8, _>(usize::MAX, 0, |_, _| {})
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 1,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_vec_stream_be_with_progress {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_read_values_and_report_progress() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut progress = vec![];
+            let values = reader
+                .read_vec_stream_be_with_progress::<u32, _>(2, 1, |read, consumed| {
+                    progress.push((read, consumed))
+                })
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, vec![0x11223344, 0xaabbccdd]);
+            assert_eq!(progress, vec![(1, 4), (2, 8)]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_count_is_larger_than_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_vec_stream_be_with_progress::<u32, _>(usize::MAX, 0, |_, _| {})
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                crate::Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod read_byte_stream_until {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_bytes_and_advance_past_the_delimiter() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x44, 0xaa, 0x0a, 0xcc, 0xdd]);
+            let first = reader
+                .read_byte_stream_until(0x0a)
+                .expect("Read should have been successful.");
+            let second = reader
+                .read_byte_stream_until(0x0a)
+                .expect("Read should have been successful.");
+
+            assert_eq!(first, vec![0x11, 0x22]);
+            assert_eq!(second, vec![0x44, 0xaa]);
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_not_advance_if_delimiter_is_not_found() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x44, 0xaa, 0x0a, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .read_byte_stream_until(0x0a)
+                .expect_err("Delimiter should not have been found");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Delimiter not found",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod read_byte_stream_until_inclusive {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_bytes_with_the_delimiter() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x44, 0xaa, 0x0a, 0xcc, 0xdd]);
+            let first = reader
+                .read_byte_stream_until_inclusive(0x0a)
+                .expect("Read should have been successful.");
+            let second = reader
+                .read_byte_stream_until_inclusive(0x0a)
+                .expect("Read should have been successful.");
+
+            assert_eq!(first, vec![0x11, 0x22, 0x0a]);
+            assert_eq!(second, vec![0x44, 0xaa, 0x0a]);
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_not_advance_if_delimiter_is_not_found() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x44, 0xaa, 0x0a, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .read_byte_stream_until_inclusive(0x0a)
+                .expect_err("Delimiter should not have been found");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Delimiter not found",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod read_stream_cstr {
+        use super::*;
+
+        #[test]
+        fn should_return_strings_and_advance_past_the_terminator() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x63, 0x00, 0x00, 0x64, 0x65]);
+
+            assert_eq!(reader.read_stream_cstr(), Ok("ab".into()));
+            assert_eq!(reader.read_stream_cstr(), Ok("c".into()));
+            assert_eq!(reader.read_stream_cstr(), Ok("".into()));
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_not_advance_if_terminator_is_missing() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x63, 0x00, 0x00, 0x64, 0x65]);
+            reader.set_index(6);
+            let error = reader
+                .read_stream_cstr()
+                .expect_err("Terminator should have been missing");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Delimiter not found",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod read_string_prefixed_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_string_and_advance_past_it() {
+            let mut reader = MockStream::new([0x03, 0x00, b'a', b'b', b'c', 0x01, b'd', 0xff]);
+            let value = reader
+                .read_string_prefixed_le::<u16>()
+                .expect("Read should have been successful.");
+            assert_eq!(value, "abc");
+            assert_eq!(reader.get_index(), 5);
+
+            let value = reader
+                .read_string_prefixed_le::<u8>()
+                .expect("Read should have been successful.");
+            assert_eq!(value, "d");
+            assert_eq!(reader.get_index(), 7);
+        }
+
+        #[test]
+        fn should_not_advance_if_length_exceeds_the_remaining_data() {
+            let mut reader = MockStream::new([0x07, 0x00, b'a', b'b', b'c', b'd', b'e', b'f']);
+            let error = reader
+                .read_string_prefixed_le::<u16>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 7,
+                    offset: 2,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+
+        #[test]
+        fn should_not_advance_if_string_is_invalid_utf8() {
+            let mut reader = MockStream::new([0x03, b'a', 0xff, 0xfe, 0, 0, 0, 0]);
+            let error = reader
+                .read_string_prefixed_le::<u8>()
+                .expect_err("String should have been invalid");
+
+            assert_eq!(error, Error::InvalidUtf8 { offset: 2 });
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    mod read_string_prefixed_be {
+        use super::*;
+
+        #[test]
+        fn should_return_a_string_and_advance_past_it() {
+            let mut reader = MockStream::new([0x00, 0x03, b'a', b'b', b'c', 0xff, 0xff, 0xff]);
+            let value = reader
+                .read_string_prefixed_be::<u16>()
+                .expect("Read should have been successful.");
+            assert_eq!(value, "abc");
+            assert_eq!(reader.get_index(), 5);
+        }
+
+        #[test]
+        fn should_read_a_u32_prefix() {
+            let mut reader = MockStream::new([0x00, 0x00, 0x00, 0x02, b'a', b'b', 0xff, 0xff]);
+            let value = reader
+                .read_string_prefixed_be::<u32>()
+                .expect("Read should have been successful.");
+            assert_eq!(value, "ab");
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_not_advance_if_length_exceeds_the_remaining_data() {
+            let mut reader = MockStream::new([0x00, 0x07, b'a', b'b', b'c', b'd', b'e', b'f']);
+            reader
+                .read_string_prefixed_be::<u16>()
+                .expect_err("Length should have been too large");
+            assert_eq!(reader.get_index(), 0);
         }
     }
 
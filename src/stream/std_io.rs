@@ -0,0 +1,107 @@
+use super::{container::StreamContainer, reader::StreamReader};
+use crate::{Cursor, Error, Reader, Writer};
+use std::io;
+
+impl From<Error> for io::Error {
+    #[inline(always)]
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::InvalidSize { .. } => io::ErrorKind::UnexpectedEof,
+            Error::InvalidAlignment { .. }
+            | Error::InvalidUtf8 { .. }
+            | Error::InvalidRead { .. } => io::ErrorKind::InvalidData,
+            Error::InvalidWrite { .. } => io::ErrorKind::InvalidInput,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+/// Reads from the current index and advances past the bytes read.
+///
+/// [Reader::read] has the same name, so call this as `io::Read::read`
+/// when both traits are in scope.
+impl<T: Reader> io::Read for StreamContainer<T> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining_slice();
+        let size = remaining.len().min(buf.len());
+        buf[..size].copy_from_slice(&remaining[..size]);
+        self.increment_by(size);
+        Ok(size)
+    }
+}
+
+/// Writes all of the bytes to the current index and advances past them.
+/// Nothing is written if the bytes don't fit.
+///
+/// [Writer::write] has the same name, so call this as `io::Write::write`
+/// when both traits are in scope.
+impl<T: Reader + Writer> io::Write for StreamContainer<T> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_bytes(index, buf)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{io::Read, vec, vec::Vec};
+
+    mod read {
+        use super::*;
+
+        #[test]
+        fn should_read_to_end_from_the_index() {
+            let data = [0x11, 0x22, 0x33, 0x44, 0x55];
+            let mut stream = StreamContainer::new(data.as_slice());
+            stream.set_index(2);
+            let mut buf = Vec::new();
+
+            assert_eq!(stream.read_to_end(&mut buf).unwrap(), 3);
+            assert_eq!(buf, [0x33, 0x44, 0x55]);
+            assert_eq!(stream.get_index(), 5);
+        }
+
+        #[test]
+        fn should_return_unexpected_eof_if_reading_past_the_end() {
+            let data = [0x11, 0x22];
+            let mut stream = StreamContainer::new(data.as_slice());
+            let mut buf = [0; 4];
+            let error = stream.read_exact(&mut buf).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        }
+    }
+
+    mod write {
+        use super::*;
+
+        #[test]
+        fn should_copy_into_a_growable_stream() {
+            let data = [0x11, 0x22, 0x33];
+            let mut stream = StreamContainer::new(vec![]);
+
+            assert_eq!(io::copy(&mut data.as_slice(), &mut stream).unwrap(), 3);
+            assert_eq!(stream.into_raw(), [0x11, 0x22, 0x33]);
+        }
+
+        #[test]
+        fn should_return_error_if_bytes_do_not_fit() {
+            let mut stream = StreamContainer::new([0; 4]);
+            stream.set_index(3);
+            let error = io::Write::write(&mut stream, &[0xaa, 0xbb]).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+            assert_eq!(stream.get_index(), 3);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Cursor, Reader, StreamReader};
+
+/// A stream that ends `limit` bytes after the index it was created at,
+/// even if the underlying data is longer.
+///
+/// Created by [StreamReader::take_stream].
+pub struct TakenStream<T: StreamReader> {
+    stream: T,
+    end: usize,
+}
+
+impl<T: StreamReader> TakenStream<T> {
+    #[inline(always)]
+    pub fn new(stream: T, limit: usize) -> Self {
+        let end = stream.get_index().saturating_add(limit);
+        Self { stream, end }
+    }
+
+    /// Consumes the taken stream and returns the underlying stream,
+    /// which keeps the index the taken stream was left at.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.stream
+    }
+}
+
+impl<T: StreamReader> Reader for TakenStream<T> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        let data = self.stream.get_slice();
+        let end = self.end.min(data.len());
+        &data[..end]
+    }
+}
+
+impl<T: StreamReader> Cursor for TakenStream<T> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.stream.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.stream.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Error, StreamContainer};
+
+    #[test]
+    fn should_read_within_the_limit() {
+        let data = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+        let mut stream = StreamContainer::new(data.as_slice());
+        stream.increment_by(1);
+        let mut taken = stream.take_stream(4);
+
+        assert_eq!(taken.read_stream_le::<u16>(), Ok(0x3322));
+        assert_eq!(taken.read_stream_le::<u16>(), Ok(0x5544));
+        assert_eq!(taken.remaining(), 0);
+    }
+
+    #[test]
+    fn should_return_error_if_reading_past_the_limit() {
+        let data = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+        let mut taken = StreamContainer::new(data.as_slice()).take_stream(3);
+        taken.read_stream_le::<u16>().unwrap();
+
+        assert_eq!(
+            taken.read_stream_le::<u16>(),
+            Err(Error::InvalidSize {
+                wanted_size: 2,
+                offset: 2,
+                data_len: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn should_end_with_the_data_if_limit_is_larger() {
+        let data = [0x11, 0x22];
+        let taken = StreamContainer::new(data.as_slice()).take_stream(10);
+
+        assert_eq!(taken.get_slice(), [0x11, 0x22]);
+    }
+
+    #[test]
+    fn should_keep_the_index_in_the_inner_stream() {
+        let data = [0x11, 0x22, 0x33, 0x44];
+        let mut taken = StreamContainer::new(data.as_slice()).take_stream(2);
+        taken.read_stream_le::<u16>().unwrap();
+        let mut stream = taken.into_inner();
+
+        assert_eq!(stream.get_index(), 2);
+        assert_eq!(stream.read_stream_le::<u16>(), Ok(0x4433));
+    }
+}
@@ -1,5 +1,9 @@
-use super::cursor::Cursor;
-use crate::{EndianWrite, Writer, WriterResult};
+use super::{
+    cursor::{padding_to_align, Cursor},
+    guard::CursorGuard,
+};
+use crate::{EndianWrite, Error, Writer, WriterResult};
+use core::convert::TryFrom;
 use safe_transmute::TriviallyTransmutable;
 
 /// An interface to write values as a stream.
@@ -63,14 +67,291 @@ pub trait StreamWriter: Writer + Cursor {
         let index = self.swap_incremented_index(bytes.len());
         self.checked_write_bytes(index, bytes)
     }
+
+    /// Writes zeros up to the next multiple of the alignment and returns the number of bytes written.
+    /// Use [Cursor::align_to] to skip the padding without writing it.
+    ///
+    /// Returns [Error::InvalidWrite] if the alignment is not a power of two.
+    #[inline(always)]
+    fn write_align_to(&mut self, alignment: usize) -> WriterResult<usize> {
+        if !alignment.is_power_of_two() {
+            return Err(Error::InvalidWrite {
+                message: "Alignment must be a power of two",
+            });
+        }
+
+        let padding = padding_to_align(self.get_index(), alignment);
+        self.write_stream_zeros(padding)
+    }
+
+    /// Writes the little endian value, then writes zeros up to the next multiple of the alignment.
+    /// Returns the number of bytes written, including the padding.
+    ///
+    /// Returns [Error::InvalidWrite] without writing if the alignment is not a power of two.
+    /// Nothing is written and the index is unchanged if the value and padding don't fit.
+    #[inline(always)]
+    fn write_stream_aligned_le<T: EndianWrite>(
+        &mut self,
+        value: &T,
+        alignment: usize,
+    ) -> WriterResult<usize> {
+        write_stream_aligned(self, value, alignment, Self::write_stream_le)
+    }
+
+    /// Same as [StreamWriter::write_stream_aligned_le], but writes a big endian value.
+    #[inline(always)]
+    fn write_stream_aligned_be<T: EndianWrite>(
+        &mut self,
+        value: &T,
+        alignment: usize,
+    ) -> WriterResult<usize> {
+        write_stream_aligned(self, value, alignment, Self::write_stream_be)
+    }
+
+    /// Writes `count` copies of `byte` and returns the number of bytes written.
+    ///
+    /// The index is unchanged if there is not enough space.
+    #[inline(always)]
+    fn write_stream_fill(&mut self, byte: u8, count: usize) -> WriterResult<usize> {
+        let index = self.get_index();
+        self.get_sized_mut_slice(index, count)?.fill(byte);
+        self.increment_by(count);
+        Ok(count)
+    }
+
+    /// Same as [StreamWriter::write_stream_fill], but writes zeros.
+    #[inline(always)]
+    fn write_stream_zeros(&mut self, count: usize) -> WriterResult<usize> {
+        self.write_stream_fill(0, count)
+    }
+
+    /// Writes each item as little endian with the `separator` bytes between consecutive items.
+    ///
+    /// No separator is written before the first item or after the last item.
+    #[inline(always)]
+    fn write_separated_le<T: EndianWrite>(
+        &mut self,
+        items: &[T],
+        separator: &[u8],
+    ) -> WriterResult<usize> {
+        let mut bytes_written = 0;
+
+        for (index, item) in items.iter().enumerate() {
+            if index != 0 {
+                bytes_written += self.write_stream_bytes(separator)?;
+            }
+            bytes_written += self.write_stream_le(item)?;
+        }
+
+        Ok(bytes_written)
+    }
+
+    /// Same as [StreamWriter::write_separated_le], but writes big endian items.
+    #[inline(always)]
+    fn write_separated_be<T: EndianWrite>(
+        &mut self,
+        items: &[T],
+        separator: &[u8],
+    ) -> WriterResult<usize> {
+        let mut bytes_written = 0;
+
+        for (index, item) in items.iter().enumerate() {
+            if index != 0 {
+                bytes_written += self.write_stream_bytes(separator)?;
+            }
+            bytes_written += self.write_stream_be(item)?;
+        }
+
+        Ok(bytes_written)
+    }
+
+    /// Writes each little endian item of an iterator and returns the total number of bytes written.
+    ///
+    /// Stops at and returns the first error. Items written before the error are kept
+    /// and the index is left after them, so the number of bytes written before the error
+    /// is the difference between the index before and after the call.
+    #[inline(always)]
+    fn write_stream_le_iter<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> WriterResult<usize> {
+        let mut bytes_written = 0;
+
+        for item in iter {
+            bytes_written += self.write_stream_le(&item)?;
+        }
+
+        Ok(bytes_written)
+    }
+
+    /// Same as [StreamWriter::write_stream_le_iter], but writes big endian items.
+    #[inline(always)]
+    fn write_stream_be_iter<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> WriterResult<usize> {
+        let mut bytes_written = 0;
+
+        for item in iter {
+            bytes_written += self.write_stream_be(&item)?;
+        }
+
+        Ok(bytes_written)
+    }
+
+    /// Writes each item of a slice as little endian and returns the total number of bytes written.
+    ///
+    /// No count is written, so the caller controls where the length is stored.
+    #[inline(always)]
+    fn write_stream_slice_le<T: EndianWrite>(&mut self, items: &[T]) -> WriterResult<usize> {
+        self.write_stream_le_iter(items)
+    }
+
+    /// Same as [StreamWriter::write_stream_slice_le], but writes big endian items.
+    #[inline(always)]
+    fn write_stream_slice_be<T: EndianWrite>(&mut self, items: &[T]) -> WriterResult<usize> {
+        self.write_stream_be_iter(items)
+    }
+
+    /// Writes the length of `value` as a little endian prefix of type `L`, followed by the string bytes.
+    ///
+    /// Returns [Error::InvalidWrite] if the length does not fit in `L`.
+    /// Nothing is written and the index is unchanged if the prefix and string don't fit.
+    #[inline(always)]
+    fn write_string_prefixed_le<L: EndianWrite + TryFrom<usize>>(
+        &mut self,
+        value: &str,
+    ) -> WriterResult<usize> {
+        write_string_prefixed(self, value, Self::write_stream_le::<L>)
+    }
+
+    /// Same as [StreamWriter::write_string_prefixed_le], but writes a big endian length prefix.
+    #[inline(always)]
+    fn write_string_prefixed_be<L: EndianWrite + TryFrom<usize>>(
+        &mut self,
+        value: &str,
+    ) -> WriterResult<usize> {
+        write_string_prefixed(self, value, Self::write_stream_be::<L>)
+    }
+
+    /// Writes a zeroed little endian prefix of type `P`, then the little endian value,
+    /// then overwrites the prefix with the number of bytes actually written for the value.
+    /// Unlike prefixes computed up front, this doesn't rely on [EndianWrite::get_size].
+    ///
+    /// Returns [Error::InvalidWrite] if the length does not fit in `P`.
+    /// The index is restored to where the prefix started if any write fails.
+    #[inline(always)]
+    fn write_deferred_length_prefixed_le<P, T>(&mut self, value: &T) -> WriterResult<usize>
+    where
+        P: EndianWrite + TryFrom<usize> + Default,
+        T: EndianWrite,
+    {
+        write_deferred_length_prefixed(
+            self,
+            value,
+            Self::write_stream_le::<P>,
+            Self::write_stream_le::<T>,
+        )
+    }
+
+    /// Same as [StreamWriter::write_deferred_length_prefixed_le], but writes big endian values.
+    #[inline(always)]
+    fn write_deferred_length_prefixed_be<P, T>(&mut self, value: &T) -> WriterResult<usize>
+    where
+        P: EndianWrite + TryFrom<usize> + Default,
+        T: EndianWrite,
+    {
+        write_deferred_length_prefixed(
+            self,
+            value,
+            Self::write_stream_be::<P>,
+            Self::write_stream_be::<T>,
+        )
+    }
 }
 
 impl<T> StreamWriter for T where T: Writer + Cursor {}
 
+/// Writes a default prefix with `write_prefix` and the value with `write_value`,
+/// then rewrites the prefix with the value's written size,
+/// like [StreamWriter::write_deferred_length_prefixed_le].
+#[inline(always)]
+fn write_deferred_length_prefixed<S, P, T>(
+    stream: &mut S,
+    value: &T,
+    write_prefix: fn(&mut S, &P) -> WriterResult<usize>,
+    write_value: fn(&mut S, &T) -> WriterResult<usize>,
+) -> WriterResult<usize>
+where
+    S: StreamWriter + ?Sized,
+    P: EndianWrite + TryFrom<usize> + Default,
+    T: EndianWrite,
+{
+    let mut guard = CursorGuard::new(stream);
+    let prefix_index = guard.get_saved_index();
+    let prefix_size = write_prefix(&mut guard, &P::default())?;
+    let value_size = write_value(&mut guard, value)?;
+    let length = P::try_from(value_size).map_err(|_| Error::InvalidWrite {
+        message: "Length does not fit in prefix",
+    })?;
+
+    let end_index = guard.get_index();
+    guard.set_index(prefix_index);
+    write_prefix(&mut guard, &length)?;
+    guard.set_index(end_index);
+    guard.commit();
+    Ok(prefix_size + value_size)
+}
+
+/// Writes `value` with `write`, then writes zeros up to the next multiple of the alignment,
+/// like [StreamWriter::write_stream_aligned_le].
+#[inline(always)]
+fn write_stream_aligned<S: StreamWriter + ?Sized, T: EndianWrite>(
+    stream: &mut S,
+    value: &T,
+    alignment: usize,
+    write: fn(&mut S, &T) -> WriterResult<usize>,
+) -> WriterResult<usize> {
+    if !alignment.is_power_of_two() {
+        return Err(Error::InvalidWrite {
+            message: "Alignment must be a power of two",
+        });
+    }
+
+    let index = stream.get_index();
+    let value_size = value.get_size();
+    let padding = padding_to_align(index.saturating_add(value_size), alignment);
+    stream.get_sized_mut_slice(index, value_size.saturating_add(padding))?;
+
+    let value_size = write(stream, value)?;
+    let padding = padding_to_align(stream.get_index(), alignment);
+    let padding_size = stream.write_stream_zeros(padding)?;
+    Ok(value_size + padding_size)
+}
+
+/// Writes the length of `value` with `write_prefix`, followed by the string bytes,
+/// like [StreamWriter::write_string_prefixed_le].
+#[inline(always)]
+fn write_string_prefixed<S: StreamWriter + ?Sized, L: EndianWrite + TryFrom<usize>>(
+    stream: &mut S,
+    value: &str,
+    write_prefix: fn(&mut S, &L) -> WriterResult<usize>,
+) -> WriterResult<usize> {
+    let length = L::try_from(value.len()).map_err(|_| Error::InvalidWrite {
+        message: "String length does not fit in prefix",
+    })?;
+    let index = stream.get_index();
+    stream.get_sized_mut_slice(index, length.get_size() + value.len())?;
+
+    let prefix_size = write_prefix(stream, &length)?;
+    let string_size = stream.write_stream_bytes(value.as_bytes())?;
+    Ok(prefix_size + string_size)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Reader;
+    use crate::{Reader, StreamContainer};
 
     pub struct MockStream {
         bytes: [u8; 8],
@@ -177,6 +458,190 @@ mod test {
         }
     }
 
+    mod write_align_to {
+        use super::*;
+
+        #[test]
+        fn should_write_zeros_to_the_next_boundary() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(3);
+            let written_length = writer
+                .write_align_to(4)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 1);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(
+                writer.get_bytes(),
+                [0xff, 0xff, 0xff, 0x00, 0xff, 0xff, 0xff, 0xff]
+            );
+        }
+
+        #[test]
+        fn should_grow_a_vector() {
+            let mut writer = StreamContainer::new(alloc::vec![0xff; 3]);
+            writer.set_index(3);
+            writer
+                .write_align_to(16)
+                .expect("Should have been written successfully");
+
+            assert_eq!(writer.get_index(), 16);
+            assert_eq!(writer.into_raw().len(), 16);
+        }
+
+        #[test]
+        fn should_not_write_if_already_aligned() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(4);
+
+            assert_eq!(writer.write_align_to(4), Ok(0));
+            assert_eq!(writer.get_index(), 4);
+        }
+
+        #[test]
+        fn should_return_error_if_alignment_is_not_a_power_of_two() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(3);
+
+            assert_eq!(
+                writer.write_align_to(3),
+                Err(Error::InvalidWrite {
+                    message: "Alignment must be a power of two"
+                })
+            );
+            assert_eq!(writer.get_index(), 3);
+        }
+
+        #[test]
+        fn should_return_error_if_padding_does_not_fit() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(7);
+
+            assert!(writer.write_align_to(16).is_err());
+            assert_eq!(writer.get_index(), 7);
+        }
+    }
+
+    mod write_stream_aligned_le {
+        use super::*;
+
+        #[test]
+        fn should_pad_the_value_to_the_alignment() {
+            let mut writer = MockStream::new([0xff; 8]);
+            let written_length = writer
+                .write_stream_aligned_le(&[0x11u8, 0x22, 0x33, 0x44, 0x55], 4)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 8);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(
+                writer.get_bytes(),
+                [0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00, 0x00]
+            );
+        }
+
+        #[test]
+        fn should_not_write_if_alignment_is_not_a_power_of_two() {
+            let mut writer = MockStream::new([0xff; 8]);
+
+            assert_eq!(
+                writer.write_stream_aligned_le(&0x11u8, 3),
+                Err(Error::InvalidWrite {
+                    message: "Alignment must be a power of two"
+                })
+            );
+            assert_eq!(writer.get_index(), 0);
+            assert_eq!(writer.get_bytes(), [0xff; 8]);
+        }
+
+        #[test]
+        fn should_not_write_if_the_padding_does_not_fit() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(4);
+
+            assert_eq!(
+                writer.write_stream_aligned_le(&0x1122u16, 16),
+                Err(Error::InvalidSize {
+                    wanted_size: 12,
+                    offset: 4,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.get_bytes(), [0xff; 8]);
+        }
+    }
+
+    mod write_stream_aligned_be {
+        use super::*;
+
+        #[test]
+        fn should_pad_the_value_to_the_alignment() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(1);
+            let written_length = writer
+                .write_stream_aligned_be(&0x1122u16, 4)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(
+                writer.get_bytes(),
+                [0xff, 0x11, 0x22, 0x00, 0xff, 0xff, 0xff, 0xff]
+            );
+        }
+    }
+
+    mod write_stream_fill {
+        use super::*;
+
+        #[test]
+        fn should_fill_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_fill(0xff, 4)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0xff, 0xff, 0xff, 0xff, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+
+            assert_eq!(
+                writer.write_stream_fill(0xff, 4),
+                Err(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                })
+            );
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_stream_zeros {
+        use super::*;
+
+        #[test]
+        fn should_write_zeros() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(1);
+
+            assert_eq!(writer.write_stream_zeros(3), Ok(3));
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(
+                writer.get_bytes(),
+                [0xff, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff]
+            );
+        }
+    }
+
     mod write_stream {
         use super::*;
         use crate::Error;
@@ -214,6 +679,29 @@ mod test {
                 }
             );
         }
+
+        #[test]
+        fn should_round_trip_with_read_stream() {
+            let mut writer = MockStream::new([0; 8]);
+            writer
+                .write_stream(&0xaabbccddu32)
+                .expect("Write should have succeeded");
+            writer
+                .write_stream(&0x11223344u32)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_index(), 8);
+
+            let mut expected = [0u8; 8];
+            expected[..4].copy_from_slice(&0xaabbccddu32.to_ne_bytes());
+            expected[4..].copy_from_slice(&0x11223344u32.to_ne_bytes());
+            assert_eq!(writer.get_bytes(), expected);
+
+            writer.set_index(0);
+            let first = crate::StreamReader::read_stream::<u32>(&mut writer);
+            let second = crate::StreamReader::read_stream::<u32>(&mut writer);
+            assert_eq!(first, Ok(0xaabbccdd));
+            assert_eq!(second, Ok(0x11223344));
+        }
     }
 
     mod checked_write {
@@ -346,4 +834,380 @@ mod test {
             assert_eq!(writer.get_bytes(), bytes);
         }
     }
+
+    mod write_separated_le {
+        use super::*;
+
+        #[test]
+        fn should_write_separators_between_items() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_separated_le(&[0x1122u16, 0x3344], &[0xff, 0xee])
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [0x22, 0x11, 0xff, 0xee, 0x44, 0x33, 0, 0]
+            );
+        }
+
+        #[test]
+        fn should_write_nothing_for_an_empty_list() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_separated_le::<u16>(&[], &[0xff])
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 0);
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+
+        #[test]
+        fn should_not_write_a_separator_for_a_single_item() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_separated_le(&[0x1122u16], &[0xff])
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_bytes(), [0x22, 0x11, 0, 0, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_error_if_items_do_not_fit() {
+            let mut writer = MockStream::new([0; 8]);
+            let error = writer
+                .write_separated_le(&[0x11223344u32, 0x55667788], &[0xff])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod write_separated_be {
+        use super::*;
+
+        #[test]
+        fn should_write_separators_between_items() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_separated_be(&[0x1122u16, 0x3344, 0x5566], &[0xff])
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 8);
+            assert_eq!(
+                writer.get_bytes(),
+                [0x11, 0x22, 0xff, 0x33, 0x44, 0xff, 0x55, 0x66]
+            );
+        }
+    }
+
+    mod write_stream_le_iter {
+        use super::*;
+
+        #[test]
+        fn should_write_each_item() {
+            let mut writer = StreamContainer::new(alloc::vec![]);
+            let items: alloc::vec::Vec<u32> = alloc::vec![0xaabbccdd, 0x11223344];
+            let written_length = writer
+                .write_stream_le_iter(items)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 8);
+            assert_eq!(
+                writer.into_raw(),
+                [0xdd, 0xcc, 0xbb, 0xaa, 0x44, 0x33, 0x22, 0x11]
+            );
+        }
+
+        #[test]
+        fn should_stop_at_the_first_error() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(2);
+            let error = writer
+                .write_stream_le_iter([1u32, 2, 3])
+                .expect_err("Write should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [0, 0, 1, 0, 0, 0, 0, 0]);
+        }
+    }
+
+    mod write_stream_be_iter {
+        use super::*;
+
+        #[test]
+        fn should_write_each_item() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_stream_be_iter([0xaabbu16, 0xccdd].iter().copied())
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0, 0]);
+        }
+    }
+
+    mod write_stream_slice_le {
+        use super::*;
+
+        #[test]
+        fn should_write_each_item() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_stream_slice_le::<u16>(&[0x2211, 0x4433, 0x6655])
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0, 0]
+            );
+        }
+
+        #[test]
+        fn should_round_trip_with_read_stream_vec_le() {
+            let values = alloc::vec![0x11223344u32, 0x55667788];
+            let mut stream = StreamContainer::new(alloc::vec![]);
+            stream
+                .write_stream_le::<u32>(&(values.len() as u32))
+                .expect("Should have been written successfully");
+            stream
+                .write_stream_slice_le(&values)
+                .expect("Should have been written successfully");
+
+            stream.set_index(0);
+            let count = crate::StreamReader::read_stream_le::<u32>(&mut stream)
+                .expect("Read should have been successful.");
+            let result =
+                crate::StreamReader::read_stream_vec_le::<u32>(&mut stream, count as usize)
+                    .expect("Read should have been successful.");
+
+            assert_eq!(result, values);
+        }
+    }
+
+    mod write_stream_slice_be {
+        use super::*;
+
+        #[test]
+        fn should_write_each_item() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_stream_slice_be::<u16>(&[0x1122, 0x3344])
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [0x11, 0x22, 0x33, 0x44, 0, 0, 0, 0]);
+        }
+    }
+
+    mod write_string_prefixed_le {
+        use super::*;
+
+        #[test]
+        fn should_write_length_and_bytes() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_string_prefixed_le::<u16>("abc")
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [0x03, 0x00, b'a', b'b', b'c', 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_error_if_length_does_not_fit_in_prefix() {
+            let mut writer = MockStream::new([0; 8]);
+            let value = "a".repeat(256);
+            let error = writer
+                .write_string_prefixed_le::<u8>(&value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String length does not fit in prefix"
+                }
+            );
+            assert_eq!(writer.get_index(), 0);
+        }
+
+        #[test]
+        fn should_round_trip_with_read_string_prefixed_le() {
+            let mut writer = MockStream::new([0; 8]);
+            writer
+                .write_string_prefixed_le::<u16>("abcd")
+                .expect("Should have been written successfully");
+            writer.set_index(0);
+
+            let value = crate::StreamReader::read_string_prefixed_le::<u16>(&mut writer)
+                .expect("Read should have been successful.");
+            assert_eq!(value, "abcd");
+        }
+    }
+
+    mod write_string_prefixed_be {
+        use super::*;
+
+        #[test]
+        fn should_write_length_and_bytes() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_string_prefixed_be::<u16>("abc")
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_bytes(), [0x00, 0x03, b'a', b'b', b'c', 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_not_write_or_advance_if_the_string_does_not_fit() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(2);
+            let error = writer
+                .write_string_prefixed_be::<u32>("abc")
+                .expect_err("String should not have fit");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 7,
+                    offset: 2,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+    }
+
+    mod write_deferred_length_prefixed_le {
+        use super::*;
+
+        /// Reports no size up front, so only the written size is correct.
+        struct Unsized;
+
+        impl EndianWrite for Unsized {
+            fn get_size(&self) -> usize {
+                0
+            }
+
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                [0xaau8, 0xbb, 0xcc].try_write_le(dst)
+            }
+
+            fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                [0xccu8, 0xbb, 0xaa].try_write_be(dst)
+            }
+        }
+
+        #[test]
+        fn should_write_the_written_length_before_the_value() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(1);
+            let written_length = writer
+                .write_deferred_length_prefixed_le::<u16, _>(&Unsized)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [0xff, 0x03, 0x00, 0xaa, 0xbb, 0xcc, 0xff, 0xff]
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_length_does_not_fit_in_prefix() {
+            let mut stream = StreamContainer::new(alloc::vec![0xff; 2]);
+            stream.set_index(2);
+            let error = stream
+                .write_deferred_length_prefixed_le::<u8, _>(&[0u16; 128])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Length does not fit in prefix"
+                }
+            );
+            assert_eq!(stream.get_index(), 2);
+        }
+
+        #[test]
+        fn should_restore_the_index_if_the_value_does_not_fit() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(4);
+            let error = writer
+                .write_deferred_length_prefixed_le::<u16, _>(&0xaabbccddu32)
+                .expect_err("Value should not have fit");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 4);
+        }
+    }
+
+    mod write_deferred_length_prefixed_be {
+        use super::*;
+
+        #[test]
+        fn should_restore_the_index_if_the_value_does_not_fit() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(2);
+            let error = writer
+                .write_deferred_length_prefixed_be::<u16, _>(&0xaabbccddu64)
+                .expect_err("Value should not have fit");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 4,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 2);
+        }
+
+        #[test]
+        fn should_write_the_written_length_before_the_value() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_deferred_length_prefixed_be::<u16, _>(&0xaabbccddu32)
+                .expect("Should have been written successfully");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [0x00, 0x04, 0xaa, 0xbb, 0xcc, 0xdd, 0, 0]
+            );
+        }
+    }
 }
@@ -1,8 +1,8 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
-use super::{add_error_context, EndianWrite, Error};
-use core::mem;
+use super::{add_error_context, EndianWrite, Error, SNorm16, SNorm8, UNorm16, UNorm8};
+use core::{convert::TryFrom, mem};
 use safe_transmute::{transmute_one_to_bytes, TriviallyTransmutable};
 
 pub type WriterResult<T> = Result<T, Error>;
@@ -37,17 +37,35 @@ pub trait Writer {
     #[inline(always)]
     fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
         let data = self.get_mut_slice();
-        let offset_end = offset + length;
+        let data_len = data.len();
 
-        if data.len() < offset_end {
-            return Err(Error::InvalidSize {
+        match offset.checked_add(length) {
+            Some(offset_end) if offset_end <= data_len => Ok(&mut data[offset..offset_end]),
+            _ => Err(Error::InvalidSize {
                 wanted_size: length,
-                data_len: data.len(),
+                data_len,
                 offset,
-            });
+            }),
         }
+    }
 
-        Ok(&mut data[offset..offset_end])
+    /// Same as [Writer::get_sized_mut_slice], but never grows the source,
+    /// so it can be used to patch bytes that were already written.
+    ///
+    /// An error is returned if `offset + size` exceeds the current data.
+    #[inline(always)]
+    fn get_mut_slice_of_size(&mut self, offset: usize, size: usize) -> WriterResult<&mut [u8]> {
+        let data = self.get_mut_slice();
+        let data_len = data.len();
+
+        match offset.checked_add(size) {
+            Some(offset_end) if offset_end <= data_len => Ok(&mut data[offset..offset_end]),
+            _ => Err(Error::InvalidSize {
+                wanted_size: size,
+                data_len,
+                offset,
+            }),
+        }
     }
 
     /// Same as [Writer::get_sized_mut_slice], except the length comes from `T.len()`.
@@ -76,6 +94,33 @@ pub trait Writer {
         self.write_bytes(offset, bytes).unwrap_or(0)
     }
 
+    /// Writes a Pascal string to the offset, which is a [u8] length followed by the UTF-8 bytes.
+    /// Returns the number of bytes written, including the length byte.
+    ///
+    /// Returns [Error::InvalidWrite] if the string is longer than 255 bytes.
+    #[inline(always)]
+    fn write_pascal_str(&mut self, offset: usize, value: &str) -> WriterResult<usize> {
+        let length = u8::try_from(value.len()).map_err(|_| Error::InvalidWrite {
+            message: "Pascal strings cannot be longer than 255 bytes",
+        })?;
+        let slice = self.get_sized_mut_slice(offset, value.len() + 1)?;
+
+        slice[0] = length;
+        slice[1..].copy_from_slice(value.as_bytes());
+        Ok(slice.len())
+    }
+
+    /// Writes 8 flags to the offset as a one byte bitmask,
+    /// where the first flag is the least significant bit.
+    #[inline(always)]
+    fn write_bool8(&mut self, offset: usize, flags: &[bool; 8]) -> WriterResult<usize> {
+        let mask = flags
+            .iter()
+            .enumerate()
+            .fold(0u8, |mask, (bit, flag)| mask | (u8::from(*flag) << bit));
+        self.write_bytes(offset, &[mask])
+    }
+
     /// Same as [Writer::write_bytes], but writes a [TriviallyTransmutable] type by converting it to bytes.
     #[inline(always)]
     fn write<T: TriviallyTransmutable>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
@@ -89,6 +134,142 @@ pub trait Writer {
         self.write(offset, value).unwrap_or(0)
     }
 
+    /// Writes a value from 0.0 to 1.0 as a [UNorm8], rounding to the nearest integer.
+    #[inline(always)]
+    fn write_unorm8(&mut self, offset: usize, value: f32) -> WriterResult<usize> {
+        self.write_le(offset, &UNorm8(value))
+    }
+
+    /// Writes a value from -1.0 to 1.0 as a [SNorm8], rounding to the nearest integer.
+    #[inline(always)]
+    fn write_snorm8(&mut self, offset: usize, value: f32) -> WriterResult<usize> {
+        self.write_le(offset, &SNorm8(value))
+    }
+
+    /// Writes a value from 0.0 to 1.0 as a little endian [UNorm16], rounding to the nearest integer.
+    #[inline(always)]
+    fn write_unorm16_le(&mut self, offset: usize, value: f32) -> WriterResult<usize> {
+        self.write_le(offset, &UNorm16(value))
+    }
+
+    /// Same as [Writer::write_unorm16_le], but writes a big endian value.
+    #[inline(always)]
+    fn write_unorm16_be(&mut self, offset: usize, value: f32) -> WriterResult<usize> {
+        self.write_be(offset, &UNorm16(value))
+    }
+
+    /// Writes a value from -1.0 to 1.0 as a little endian [SNorm16], rounding to the nearest integer.
+    #[inline(always)]
+    fn write_snorm16_le(&mut self, offset: usize, value: f32) -> WriterResult<usize> {
+        self.write_le(offset, &SNorm16(value))
+    }
+
+    /// Same as [Writer::write_snorm16_le], but writes a big endian value.
+    #[inline(always)]
+    fn write_snorm16_be(&mut self, offset: usize, value: f32) -> WriterResult<usize> {
+        self.write_be(offset, &SNorm16(value))
+    }
+
+    /// Writes the lowest `byte_width` bytes of a two's complement integer in little endian.
+    ///
+    /// Returns [Error::InvalidWrite] if `byte_width` is 0 or greater than 8,
+    /// or if the value can't be represented in `byte_width` bytes.
+    #[inline(always)]
+    fn write_signed_le(
+        &mut self,
+        offset: usize,
+        value: i64,
+        byte_width: usize,
+    ) -> WriterResult<usize> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidWrite {
+                message: "Invalid byte width",
+            });
+        }
+
+        let shift = 64 - (byte_width * 8);
+        if (value << shift) >> shift != value {
+            return Err(Error::InvalidWrite {
+                message: "Value does not fit in byte width",
+            });
+        }
+
+        self.write_bytes(offset, &value.to_le_bytes()[..byte_width])
+    }
+
+    /// Same as [Writer::write_signed_le], but writes a big endian integer.
+    #[inline(always)]
+    fn write_signed_be(
+        &mut self,
+        offset: usize,
+        value: i64,
+        byte_width: usize,
+    ) -> WriterResult<usize> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidWrite {
+                message: "Invalid byte width",
+            });
+        }
+
+        let shift = 64 - (byte_width * 8);
+        if (value << shift) >> shift != value {
+            return Err(Error::InvalidWrite {
+                message: "Value does not fit in byte width",
+            });
+        }
+
+        self.write_bytes(offset, &value.to_be_bytes()[8 - byte_width..])
+    }
+
+    /// Writes the lowest `byte_width` bytes of an unsigned integer in little endian.
+    ///
+    /// Returns [Error::InvalidWrite] if `byte_width` is 0 or greater than 8,
+    /// or if the value can't be represented in `byte_width` bytes.
+    #[inline(always)]
+    fn write_unsigned_le(
+        &mut self,
+        offset: usize,
+        value: u64,
+        byte_width: usize,
+    ) -> WriterResult<usize> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidWrite {
+                message: "Invalid byte width",
+            });
+        }
+
+        if byte_width < 8 && value >> (byte_width * 8) != 0 {
+            return Err(Error::InvalidWrite {
+                message: "Value does not fit in byte width",
+            });
+        }
+
+        self.write_bytes(offset, &value.to_le_bytes()[..byte_width])
+    }
+
+    /// Same as [Writer::write_unsigned_le], but writes a big endian integer.
+    #[inline(always)]
+    fn write_unsigned_be(
+        &mut self,
+        offset: usize,
+        value: u64,
+        byte_width: usize,
+    ) -> WriterResult<usize> {
+        if byte_width == 0 || byte_width > 8 {
+            return Err(Error::InvalidWrite {
+                message: "Invalid byte width",
+            });
+        }
+
+        if byte_width < 8 && value >> (byte_width * 8) != 0 {
+            return Err(Error::InvalidWrite {
+                message: "Value does not fit in byte width",
+            });
+        }
+
+        self.write_bytes(offset, &value.to_be_bytes()[8 - byte_width..])
+    }
+
     /// Writes a value in its little endian representation.
     ///
     /// Prefer endian agnostic methods when possible.
@@ -111,6 +292,13 @@ pub trait Writer {
         self.write_le(offset, value).unwrap_or(0)
     }
 
+    /// Same as [Writer::write_le], but returns the offset after the written value,
+    /// which is where the next value should be written.
+    #[inline(always)]
+    fn write_le_end<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        Ok(offset + self.write_le(offset, value)?)
+    }
+
     /// Writes a value in its big endian representation.
     ///
     /// Prefer endian agnostic methods when possible.
@@ -132,6 +320,13 @@ pub trait Writer {
     fn checked_write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> usize {
         self.write_be(offset, value).unwrap_or(0)
     }
+
+    /// Same as [Writer::write_be], but returns the offset after the written value,
+    /// which is where the next value should be written.
+    #[inline(always)]
+    fn write_be_end<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        Ok(offset + self.write_be(offset, value)?)
+    }
 }
 
 impl<const SIZE: usize> Writer for [u8; SIZE] {
@@ -157,12 +352,7 @@ impl Writer for Vec<u8> {
 
     #[inline(always)]
     fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
-        let offset_end = offset + value.get_size();
-        let self_len = self.len();
-
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
-        }
+        resize_to_fit(self, offset, value.get_size())?;
 
         add_error_context(
             value.try_write_le(&mut self[offset..]),
@@ -173,12 +363,7 @@ impl Writer for Vec<u8> {
 
     #[inline(always)]
     fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
-        let offset_end = offset + value.get_size();
-        let self_len = self.len();
-
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
-        }
+        resize_to_fit(self, offset, value.get_size())?;
 
         add_error_context(
             value.try_write_be(&mut self[offset..]),
@@ -189,18 +374,32 @@ impl Writer for Vec<u8> {
 
     #[inline(always)]
     fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
-        let offset_end = offset + length;
-        let self_len = self.len();
-
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
-        }
-
+        let offset_end = resize_to_fit(self, offset, length)?;
         let slice = self.get_mut_slice();
         Ok(&mut slice[offset..offset_end])
     }
 }
 
+/// Grows the vector with zeros if needed so `length` bytes fit at the offset,
+/// and returns the end of those bytes.
+///
+/// An error is returned if `offset + length` overflows.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn resize_to_fit(vec: &mut Vec<u8>, offset: usize, length: usize) -> WriterResult<usize> {
+    let offset_end = offset.checked_add(length).ok_or(Error::InvalidSize {
+        wanted_size: length,
+        offset,
+        data_len: vec.len(),
+    })?;
+
+    if offset_end > vec.len() {
+        vec.resize(offset_end, 0);
+    }
+
+    Ok(offset_end)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -232,6 +431,107 @@ mod test {
         }
     }
 
+    mod array_writer {
+        use super::*;
+
+        #[test]
+        fn should_write_into_a_stack_array() {
+            let mut writer = [0u8; 8];
+            let written_length = writer
+                .write_le(0, &0xaabbccddu32)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [0xdd, 0xcc, 0xbb, 0xaa, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_not_grow_a_stack_array() {
+            let mut writer = [0u8; 8];
+            let error = writer
+                .write_be(6, &0xaabbccddu32)
+                .expect_err("Write should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer, [0; 8]);
+        }
+    }
+
+    mod get_mut_slice_of_size {
+        use super::*;
+
+        use alloc::{vec, vec::Vec};
+
+        #[test]
+        fn should_patch_bytes_at_an_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer
+                .get_mut_slice_of_size(2, 2)
+                .expect("Should have succeeded")
+                .copy_from_slice(&[0xaa, 0xbb]);
+
+            assert_eq!(writer.get_mut_slice(), [1, 2, 0xaa, 0xbb, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .get_mut_slice_of_size(6, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_not_grow_a_vector() {
+            let mut writer: Vec<u8> = vec![1, 2, 3, 4];
+            let error = writer
+                .get_mut_slice_of_size(2, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 2,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(writer, [1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn should_patch_a_checksum_after_writing_a_body() {
+            let mut writer: Vec<u8> = vec![];
+            writer.write_le(2, &0xaabbccddu32).unwrap();
+
+            let checksum = writer[2..]
+                .iter()
+                .fold(0u16, |sum, byte| sum + *byte as u16);
+            writer
+                .get_mut_slice_of_size(0, 2)
+                .expect("Should have succeeded")
+                .copy_from_slice(&checksum.to_le_bytes());
+
+            assert_eq!(writer, [0x0e, 0x03, 0xdd, 0xcc, 0xbb, 0xaa]);
+        }
+    }
+
     mod get_sized_mut_slice {
         use super::*;
 
@@ -291,6 +591,24 @@ mod test {
             assert_eq!(writer.len(), 4);
         }
 
+        #[test]
+        fn should_return_error_if_a_vector_offset_overflows() {
+            let mut writer: Vec<u8> = vec![0; 4];
+            let error = writer
+                .get_sized_mut_slice(usize::MAX, 2)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: usize::MAX,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(writer.len(), 4);
+        }
+
         #[test]
         fn should_not_not_error_if_vector_size_is_larger_than_write_size() {
             let mut writer: Vec<u8> = vec![0; 10];
@@ -355,6 +673,17 @@ mod test {
             assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
         }
 
+        #[test]
+        fn should_leave_bytes_after_the_slice_unchanged() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_bytes(4, &[0xaa, 0xbb, 0xcc])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0xaa, 0xbb, 0xcc, 8]);
+        }
+
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
@@ -442,6 +771,41 @@ mod test {
             assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd]);
             assert_eq!(writer.len(), 4);
         }
+
+        #[test]
+        fn should_return_error_if_a_vector_offset_overflows_with_le() {
+            let mut writer = vec![0; 4];
+            let error = writer
+                .write_le(usize::MAX - 1, &0xaabbccddu32)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: usize::MAX - 1,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(writer, vec![0; 4]);
+        }
+
+        #[test]
+        fn should_return_error_if_a_vector_offset_overflows_with_be() {
+            let mut writer = vec![0; 4];
+            let error = writer
+                .write_be(usize::MAX, &0xaabbu16)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: usize::MAX,
+                    data_len: 4,
+                }
+            );
+        }
     }
 
     mod checked_write_bytes {
@@ -471,6 +835,75 @@ mod test {
         }
     }
 
+    mod write_bool8 {
+        use super::*;
+
+        #[test]
+        fn should_pack_the_flags_lsb_first() {
+            let mut writer = MockWriter::new([0; 8]);
+            let flags = [true, false, false, false, false, true, false, true];
+
+            assert_eq!(writer.write_bool8(1, &flags), Ok(1));
+            assert_eq!(writer.get_bytes(), [0, 0b1010_0001, 0, 0, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_round_trip_with_read_bool8() {
+            let mut writer = MockWriter::new([0, 0b1010_0001, 0, 0, 0, 0, 0, 0]);
+            let flags = writer
+                .read_bool8(1)
+                .expect("Read should have been successful.");
+            writer
+                .write_bool8(2, &flags)
+                .expect("Write should have succeeded");
+
+            assert_eq!(writer.get_bytes()[2], 0b1010_0001);
+        }
+    }
+
+    mod write_pascal_str {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_write_a_string() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer
+                .write_pascal_str(1, "abc")
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [0, 3, 0x61, 0x62, 0x63, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_error_if_string_is_too_long() {
+            let mut writer = vec![];
+            let value = "a".repeat(256);
+            let error = writer
+                .write_pascal_str(0, &value)
+                .expect_err("String should have been too long");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Pascal strings cannot be longer than 255 bytes",
+                }
+            );
+            assert!(writer.is_empty());
+        }
+
+        #[test]
+        fn should_round_trip() {
+            let mut writer = vec![];
+            let value = "a".repeat(255);
+            writer.write_pascal_str(0, &value).unwrap();
+            let result = writer.read_pascal_str(0).unwrap();
+
+            assert_eq!(result, crate::ReadOutput::new(value.as_str(), 256));
+        }
+    }
+
     mod write {
         use super::*;
 
@@ -534,6 +967,234 @@ mod test {
         }
     }
 
+    mod write_unorm8 {
+        use super::*;
+
+        #[test]
+        fn should_write_a_rounded_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_unorm8(0, 1.0), Ok(1));
+            assert_eq!(writer.write_unorm8(1, 0.5), Ok(1));
+            assert_eq!(writer.get_mut_slice()[..2], [0xff, 0x80]);
+        }
+    }
+
+    mod write_snorm8 {
+        use super::*;
+
+        #[test]
+        fn should_write_a_rounded_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_snorm8(0, -1.0), Ok(1));
+            assert_eq!(writer.write_snorm8(1, 0.5), Ok(1));
+            assert_eq!(writer.get_mut_slice()[..2], [0x81, 0x40]);
+        }
+    }
+
+    mod write_unorm16 {
+        use super::*;
+
+        #[test]
+        fn should_write_a_rounded_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_unorm16_le(0, 0.5), Ok(2));
+            assert_eq!(writer.write_unorm16_be(2, 0.5), Ok(2));
+            assert_eq!(writer.get_mut_slice()[..4], [0x00, 0x80, 0x80, 0x00]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_unorm16_le(7, 1.0)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod write_snorm16 {
+        use super::*;
+
+        #[test]
+        fn should_write_a_rounded_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_snorm16_le(0, -0.5), Ok(2));
+            assert_eq!(writer.write_snorm16_be(2, 1.0), Ok(2));
+            assert_eq!(writer.get_mut_slice()[..4], [0x00, 0xc0, 0x7f, 0xff]);
+        }
+    }
+
+    mod write_signed_le {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer
+                .write_signed_le(1, -2, 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [0, 0xfe, 0xff, 0xff, 0, 0, 0, 0]);
+            assert_eq!(writer.read_signed_le(1, 3), Ok(-2));
+        }
+
+        #[test]
+        fn should_write_the_bounds_of_a_width() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer.write_signed_le(0, 0x7fffff, 3).unwrap();
+            writer.write_signed_le(3, -0x800000, 3).unwrap();
+
+            assert_eq!(
+                writer.get_bytes(),
+                [0xff, 0xff, 0x7f, 0x00, 0x00, 0x80, 0, 0]
+            );
+        }
+
+        #[test]
+        fn should_write_full_width_values() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer.write_signed_le(0, i64::MIN, 8).unwrap();
+
+            assert_eq!(writer.read_signed_le(0, 8), Ok(i64::MIN));
+        }
+
+        #[test]
+        fn should_return_error_if_value_does_not_fit() {
+            let mut writer = MockWriter::new([0; 8]);
+
+            for value in [0x800000, -0x800001] {
+                let error = writer
+                    .write_signed_le(0, value, 3)
+                    .expect_err("Value should not have fit");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidWrite {
+                        message: "Value does not fit in byte width",
+                    }
+                );
+            }
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_widths() {
+            let mut writer = MockWriter::new([0; 8]);
+
+            for byte_width in [0, 9] {
+                let error = writer
+                    .write_signed_le(0, 0, byte_width)
+                    .expect_err("Byte width should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidWrite {
+                        message: "Invalid byte width",
+                    }
+                );
+            }
+        }
+    }
+
+    mod write_signed_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer
+                .write_signed_be(1, -2, 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [0, 0xff, 0xff, 0xfe, 0, 0, 0, 0]);
+            assert_eq!(writer.read_signed_be(1, 3), Ok(-2));
+        }
+    }
+
+    mod write_unsigned_le {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer
+                .write_unsigned_le(1, 0xaabbcc, 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [0, 0xcc, 0xbb, 0xaa, 0, 0, 0, 0]);
+            assert_eq!(writer.read_unsigned_le(1, 3), Ok(0xaabbcc));
+        }
+
+        #[test]
+        fn should_write_full_width_values() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer.write_unsigned_le(0, u64::MAX, 8).unwrap();
+
+            assert_eq!(writer.get_bytes(), [0xff; 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_value_does_not_fit() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_unsigned_le(0, 0x1000000, 3)
+                .expect_err("Value should not have fit");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Value does not fit in byte width",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_widths() {
+            let mut writer = MockWriter::new([0; 8]);
+
+            for byte_width in [0, 9] {
+                let error = writer
+                    .write_unsigned_le(0, 0, byte_width)
+                    .expect_err("Byte width should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidWrite {
+                        message: "Invalid byte width",
+                    }
+                );
+            }
+        }
+    }
+
+    mod write_unsigned_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer
+                .write_unsigned_be(1, 0xaabbcc, 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [0, 0xaa, 0xbb, 0xcc, 0, 0, 0, 0]);
+            assert_eq!(writer.read_unsigned_be(1, 3), Ok(0xaabbcc));
+        }
+    }
+
     mod write_le {
         use super::*;
         use alloc::vec;
@@ -720,6 +1381,45 @@ mod test {
         }
     }
 
+    mod write_le_end {
+        use super::*;
+
+        #[test]
+        fn should_return_the_next_offset() {
+            let mut writer = MockWriter::new([0; 8]);
+            let offset = writer
+                .write_le_end(0, &0xaabbccddu32)
+                .expect("Write should have succeeded");
+            let end = writer
+                .write_le_end(offset, &0x11223344u32)
+                .expect("Write should have succeeded");
+
+            assert_eq!(offset, 4);
+            assert_eq!(end, 8);
+            assert_eq!(
+                writer.get_bytes(),
+                [0xdd, 0xcc, 0xbb, 0xaa, 0x44, 0x33, 0x22, 0x11]
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_le_end(6, &0xaabbccddu32)
+                .expect_err("Write should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
     mod write_be {
         use super::*;
         use alloc::vec;
@@ -905,4 +1605,23 @@ mod test {
             assert_eq!(writer.get_bytes(), bytes);
         }
     }
+
+    mod write_be_end {
+        use super::*;
+
+        #[test]
+        fn should_return_the_next_offset() {
+            let mut writer = MockWriter::new([0; 8]);
+            let offset = writer
+                .write_be_end(1, &0xaabbu16)
+                .expect("Write should have succeeded");
+            let end = writer
+                .write_be_end(offset, &0xccddu16)
+                .expect("Write should have succeeded");
+
+            assert_eq!(offset, 3);
+            assert_eq!(end, 5);
+            assert_eq!(writer.get_bytes(), [0, 0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0]);
+        }
+    }
 }